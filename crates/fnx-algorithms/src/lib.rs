@@ -3,7 +3,8 @@
 
 use fnx_cgse::{
     ReferenceAlgorithm as CgseReferenceAlgorithm, WitnessSink as CgseWitnessSink,
-    with_ledger as with_cgse_ledger, witness_collection_enabled as cgse_witness_collection_enabled,
+    analytic_upper_bound as cgse_analytic_upper_bound, with_ledger as with_cgse_ledger,
+    witness_collection_enabled as cgse_witness_collection_enabled,
 };
 use fnx_classes::digraph::DiGraph;
 use fnx_classes::{AttrMap, Graph};
@@ -26,6 +27,15 @@ pub use network_simplex_int::{
     network_simplex_int,
 };
 
+/// Seeded, deterministic graph mutators (edge removal, edge addition,
+/// degree-preserving rewiring) and per-node metric sensitivity reports.
+pub mod perturb;
+
+/// Catalog fixtures, witnessed runs and structured-log builders for
+/// conformance packets; behind the `test_support` feature.
+#[cfg(any(test, feature = "test_support"))]
+pub mod test_support;
+
 pub const CGSE_WITNESS_ARTIFACT_SCHEMA_VERSION_V1: &str = "1.0.0";
 pub const CGSE_WITNESS_POLICY_SPEC_PATH: &str =
     "artifacts/cgse/v1/cgse_deterministic_policy_spec_v1.json";
@@ -182,6 +192,31 @@ impl Ord for PrimReferenceIndexEdge {
     }
 }
 
+/// Frontier entry of the bottleneck-path search: the largest `key` pops first,
+/// then the smallest node name.
+#[derive(Clone, Copy, PartialEq)]
+struct BottleneckState<'a> {
+    key: f64,
+    name: &'a str,
+    node: usize,
+}
+
+impl Eq for BottleneckState<'_> {}
+
+impl PartialOrd for BottleneckState<'_> {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for BottleneckState<'_> {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.key
+            .total_cmp(&other.key)
+            .then_with(|| other.name.cmp(self.name))
+    }
+}
+
 pub trait GraphView {
     fn nodes_ordered(&self) -> Vec<&str>;
     fn get_node_index(&self, node: &str) -> Option<usize>;
@@ -441,6 +476,245 @@ impl ComplexityWitness {
     }
 }
 
+/// Reads one work counter off a `ComplexityWitness`.
+type WitnessCounterAccessor = fn(&ComplexityWitness) -> usize;
+
+/// Work counters of `ComplexityWitness` and their accessors, in the order
+/// delta reports list them.
+const WITNESS_DELTA_COUNTERS: [(&str, WitnessCounterAccessor); 3] = [
+    ("nodes_touched", |witness| witness.nodes_touched),
+    ("edges_scanned", |witness| witness.edges_scanned),
+    ("queue_peak", |witness| witness.queue_peak),
+];
+
+/// Multiplier on the analytic cost delta, mirroring the 2x constant-factor
+/// allowance of `fnx_cgse::verify_complexity_bound`.
+const WITNESS_DELTA_CONSTANT_FACTOR: u64 = 2;
+
+/// Additive allowance absorbing traversal-order jitter (early exits, tie-break
+/// changes) that a small edit can cause independently of the cost model.
+const WITNESS_DELTA_SLACK: u64 = 16;
+
+/// Node and edge counts of one graph revision, the inputs to `expected_delta_bounds`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct GraphSizeStats {
+    pub nodes: usize,
+    pub edges: usize,
+}
+
+impl GraphSizeStats {
+    #[must_use]
+    pub fn of<G: GraphView + ?Sized>(graph: &G) -> Self {
+        Self {
+            nodes: graph.node_count(),
+            edges: graph.edge_count(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct WitnessCounterDelta {
+    pub counter: String,
+    pub old: usize,
+    pub new: usize,
+    /// `new - old`.
+    pub absolute: i128,
+    /// `(new - old) / old`; `None` when the old counter is zero.
+    pub relative: Option<f64>,
+}
+
+/// Per-counter change between two witnesses of the same algorithm, e.g. before
+/// and after a small graph edit in an incremental pipeline.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct WitnessDelta {
+    pub old_algorithm: String,
+    pub new_algorithm: String,
+    pub counters: Vec<WitnessCounterDelta>,
+}
+
+impl WitnessDelta {
+    #[must_use]
+    pub fn between(old: &ComplexityWitness, new: &ComplexityWitness) -> Self {
+        let counters = WITNESS_DELTA_COUNTERS
+            .into_iter()
+            .map(|(counter, value_of)| {
+                let (old_value, new_value) = (value_of(old), value_of(new));
+                let absolute = new_value as i128 - old_value as i128;
+                WitnessCounterDelta {
+                    counter: counter.to_owned(),
+                    old: old_value,
+                    new: new_value,
+                    absolute,
+                    relative: (old_value != 0).then(|| absolute as f64 / old_value as f64),
+                }
+            })
+            .collect();
+        Self {
+            old_algorithm: old.algorithm.clone(),
+            new_algorithm: new.algorithm.clone(),
+            counters,
+        }
+    }
+
+    #[must_use]
+    pub fn counter(&self, counter: &str) -> Option<&WitnessCounterDelta> {
+        self.counters.iter().find(|delta| delta.counter == counter)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WitnessCounterBound {
+    pub counter: String,
+    pub max_abs_delta: u64,
+}
+
+/// Loose per-counter limits on how far a witness may move between two graph
+/// revisions, derived from the algorithm's dominant complexity term.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ExpectedDeltaBounds {
+    pub dominant_term: String,
+    pub old_stats: GraphSizeStats,
+    pub new_stats: GraphSizeStats,
+    pub counters: Vec<WitnessCounterBound>,
+}
+
+/// Bounds each witness counter's change by
+/// `2 * |f(new) - f(old)| + 16`, where `f` is `fnx_cgse::analytic_upper_bound`
+/// for `dominant_term` (the `fnx_cgse::ReferenceAlgorithm::dominant_complexity`
+/// vocabulary, e.g. `"n_plus_m"`). Returns `None` for terms the cost model does
+/// not know. The same limit applies to every counter; callers may tighten
+/// individual entries of `counters`.
+#[must_use]
+pub fn expected_delta_bounds(
+    old_stats: GraphSizeStats,
+    new_stats: GraphSizeStats,
+    dominant_term: &str,
+) -> Option<ExpectedDeltaBounds> {
+    let old_cost = cgse_analytic_upper_bound(dominant_term, old_stats.nodes, old_stats.edges)?;
+    let new_cost = cgse_analytic_upper_bound(dominant_term, new_stats.nodes, new_stats.edges)?;
+    let max_abs_delta = old_cost
+        .abs_diff(new_cost)
+        .saturating_mul(WITNESS_DELTA_CONSTANT_FACTOR)
+        .saturating_add(WITNESS_DELTA_SLACK);
+    Some(ExpectedDeltaBounds {
+        dominant_term: dominant_term.to_owned(),
+        old_stats,
+        new_stats,
+        counters: WITNESS_DELTA_COUNTERS
+            .into_iter()
+            .map(|(counter, _)| WitnessCounterBound {
+                counter: counter.to_owned(),
+                max_abs_delta,
+            })
+            .collect(),
+    })
+}
+
+/// Stopgap mapping from a witness `complexity_claim` string to the dominant-term
+/// vocabulary accepted by `expected_delta_bounds`. Only claims whose cost model
+/// `fnx_cgse::analytic_upper_bound` covers are recognized.
+#[must_use]
+pub fn dominant_term_from_complexity_claim(claim: &str) -> Option<&'static str> {
+    let normalized = claim
+        .chars()
+        .filter(|character| !character.is_whitespace())
+        .collect::<String>();
+    Some(match normalized.as_str() {
+        "O(|V|)" => "n",
+        "O(|E|)" => "m",
+        "O(|V|+|E|)" => "n_plus_m",
+        "O(|V|log|V|)" => "n_log_n",
+        "O(|V|+|E|log|V|)" | "O(|E|log|V|+|V|)" => "n_plus_m_log_n",
+        "O(|V|*|E|)" => "n_m",
+        "O(|V|^2)" => "n_squared",
+        "O(|E|log|E|)" => "m_log_m",
+        "O(|E|log|V|)" => "m_log_n",
+        _ => return None,
+    })
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WitnessDeltaViolation {
+    pub counter: String,
+    pub absolute_delta: u128,
+    pub max_abs_delta: u64,
+}
+
+/// Outcome of `assert_witness_delta_within` when the witnesses disagree with the bounds.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct WitnessDeltaReport {
+    pub delta: WitnessDelta,
+    pub bounds: ExpectedDeltaBounds,
+    /// The two witnesses name different algorithms, so their counters are not comparable.
+    pub algorithm_mismatch: bool,
+    pub violations: Vec<WitnessDeltaViolation>,
+}
+
+impl fmt::Display for WitnessDeltaReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "witness delta for {} exceeds {} bounds",
+            self.delta.new_algorithm, self.bounds.dominant_term
+        )?;
+        if self.algorithm_mismatch {
+            write!(
+                f,
+                "; algorithm changed from {} to {}",
+                self.delta.old_algorithm, self.delta.new_algorithm
+            )?;
+        }
+        for violation in &self.violations {
+            write!(
+                f,
+                "; {} moved by {} (allowed {})",
+                violation.counter, violation.absolute_delta, violation.max_abs_delta
+            )?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for WitnessDeltaReport {}
+
+/// Test-friendly check that every counter moved by at most its bound.
+///
+/// Returns the computed delta on success and a report naming each offending
+/// counter otherwise; it never panics.
+pub fn assert_witness_delta_within(
+    old: &ComplexityWitness,
+    new: &ComplexityWitness,
+    bounds: &ExpectedDeltaBounds,
+) -> Result<WitnessDelta, Box<WitnessDeltaReport>> {
+    let delta = WitnessDelta::between(old, new);
+    let violations = delta
+        .counters
+        .iter()
+        .filter_map(|counter_delta| {
+            let bound = bounds
+                .counters
+                .iter()
+                .find(|bound| bound.counter == counter_delta.counter)?;
+            let absolute_delta = counter_delta.absolute.unsigned_abs();
+            (absolute_delta > u128::from(bound.max_abs_delta)).then(|| WitnessDeltaViolation {
+                counter: counter_delta.counter.clone(),
+                absolute_delta,
+                max_abs_delta: bound.max_abs_delta,
+            })
+        })
+        .collect::<Vec<_>>();
+    let algorithm_mismatch = old.algorithm != new.algorithm;
+    if violations.is_empty() && !algorithm_mismatch {
+        return Ok(delta);
+    }
+    Err(Box::new(WitnessDeltaReport {
+        delta,
+        bounds: bounds.clone(),
+        algorithm_mismatch,
+        violations,
+    }))
+}
+
 fn cgse_begin(reference: CgseReferenceAlgorithm) -> Option<CgseWitnessSink> {
     cgse_witness_collection_enabled().then(|| CgseWitnessSink::new(reference.policy()))
 }
@@ -477,6 +751,57 @@ pub struct ShortestPathResult {
     pub witness: ComplexityWitness,
 }
 
+/// Frontier discipline for `shortest_path_unweighted_with_order`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum FrontierOrder {
+    /// First-seen insertion order; identical to `shortest_path_unweighted`.
+    #[default]
+    Fifo,
+    /// Priority frontier keyed by node name (see `lexicographic_bfs_order`), so the
+    /// lexicographically smallest of several equal-length paths is returned.
+    Lexicographic,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PriorityBfsResult {
+    /// Nodes in visit order, starting with the source.
+    pub order: Vec<String>,
+    /// `(node, parent)` aligned with `order`; the source has no parent.
+    pub parents: Vec<(String, Option<String>)>,
+    pub witness: ComplexityWitness,
+}
+
+/// Largest waypoint set `shortest_path_via_waypoints` will order exactly; the
+/// bitmask DP is `O(2^k k^2)` on top of the pairwise leg searches.
+pub const MAX_UNORDERED_WAYPOINTS: usize = 10;
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WaypointLeg {
+    pub source: String,
+    pub target: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BottleneckPathResult {
+    pub path: Option<Vec<String>>,
+    /// Smallest capacity (widest path) or largest weight (minimax path) along
+    /// `path`; `None` when there is no path or it has no edges.
+    pub bottleneck: Option<f64>,
+    pub witness: ComplexityWitness,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct WaypointRouteResult {
+    /// Concatenated node path with the junction node of consecutive legs kept once.
+    pub path: Option<Vec<String>>,
+    pub total_weight: Option<f64>,
+    /// Waypoints in the order they are visited.
+    pub waypoint_order: Vec<String>,
+    /// First leg with no path, set whenever `path` is `None`.
+    pub unreachable_leg: Option<WaypointLeg>,
+    pub witness: ComplexityWitness,
+}
+
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct WeightedDistanceEntry {
     pub node: String,
@@ -527,6 +852,15 @@ pub struct ClosenessCentralityResult {
     pub witness: ComplexityWitness,
 }
 
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct NodeWeightedCentralityResult {
+    pub scores: Vec<CentralityScore>,
+    /// Nodes, in node order, whose weight attribute was absent or unusable and
+    /// therefore counted as 1.0.
+    pub defaulted_weight_nodes: Vec<String>,
+    pub witness: ComplexityWitness,
+}
+
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct HarmonicCentralityResult {
     pub scores: Vec<CentralityScore>,
@@ -594,6 +928,88 @@ pub struct MaximalMatchingResult {
 pub struct WeightedMatchingResult {
     pub matching: Vec<(String, String)>,
     pub total_weight: f64,
+    /// Exact decimal total; only populated by the `_with_precision` variants.
+    pub total_weight_exact: Option<String>,
+    pub weight_diagnostics: Vec<WeightPrecisionDiagnostic>,
+    pub witness: ComplexityWitness,
+}
+
+/// Which total `degree_constrained_subgraph` optimizes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum DegreeConstrainedObjective {
+    MinimizeWeight,
+    MaximizeWeight,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DegreeConstrainedSubgraphResult {
+    /// Selected edges in canonical order (`left <= right`, sorted).
+    pub selected_edges: Vec<(String, String)>,
+    pub total_weight: f64,
+    /// Degree of every node in the selection, in graph node order.
+    pub degrees: Vec<(String, usize)>,
+    pub feasible: bool,
+    /// First node, in graph order, whose bounds the selection misses.
+    pub violated_node: Option<String>,
+    pub witness: ComplexityWitness,
+}
+
+/// How weighted totals are accumulated.
+///
+/// `Float` is the historical f64 sum. `Decimal(places)` additionally parses every
+/// contributing weight as a decimal string into an `i128` scaled by `10^places`
+/// and sums it exactly; `places` above [`MAX_DECIMAL_WEIGHT_PLACES`] is rejected
+/// with [`WeightPrecisionError::UnsupportedPlaces`]. Weights that do not fit are
+/// reported as [`WeightPrecisionDiagnostic`]s instead of being rounded.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum WeightPrecision {
+    #[default]
+    Float,
+    Decimal(u32),
+}
+
+/// Largest decimal scale accepted by `WeightPrecision::Decimal`; larger requests
+/// are rejected. `10^30` leaves eight integer digits of `i128` headroom.
+pub const MAX_DECIMAL_WEIGHT_PLACES: u32 = 30;
+
+impl WeightPrecision {
+    /// Requested decimal places, `None` for `Float`.
+    fn decimal_places(self) -> Result<Option<u32>, WeightPrecisionError> {
+        match self {
+            Self::Float => Ok(None),
+            Self::Decimal(places) if places > MAX_DECIMAL_WEIGHT_PLACES => {
+                Err(WeightPrecisionError::UnsupportedPlaces {
+                    places,
+                    max: MAX_DECIMAL_WEIGHT_PLACES,
+                })
+            }
+            Self::Decimal(places) => Ok(Some(places)),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum WeightPrecisionIssue {
+    /// The weight has non-zero digits beyond the requested decimal places.
+    PrecisionLoss,
+    /// The scaled weight, or the running total after adding it, exceeds `i128`.
+    Overflow,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WeightPrecisionDiagnostic {
+    pub left: String,
+    pub right: String,
+    pub raw_value: String,
+    pub issue: WeightPrecisionIssue,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct WeightedPathCostResult {
+    pub path: Option<Vec<String>>,
+    pub total_weight: Option<f64>,
+    pub total_weight_exact: Option<String>,
+    pub weight_diagnostics: Vec<WeightPrecisionDiagnostic>,
     pub witness: ComplexityWitness,
 }
 
@@ -756,6 +1172,48 @@ pub struct NodeTriangleCount {
     pub count: usize,
 }
 
+/// Orbit labels in the standard (Pržulj) numbering 0..=14; orbits 0..=3 cover
+/// the 2- and 3-node graphlets.
+pub const GRAPHLET_ORBIT_LABELS: [&str; 15] = [
+    "edge",
+    "path3_end",
+    "path3_center",
+    "triangle",
+    "path4_end",
+    "path4_inner",
+    "star3_leaf",
+    "star3_center",
+    "cycle4",
+    "paw_pendant",
+    "paw_triangle",
+    "paw_center",
+    "diamond_degree2",
+    "diamond_degree3",
+    "clique4",
+];
+
+/// Connected graphlets in orbit order; the first three have at most 3 nodes.
+pub const GRAPHLET_LABELS: [&str; 9] = [
+    "edge", "path3", "triangle", "path4", "star3", "cycle4", "paw", "diamond", "clique4",
+];
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct GraphletOrbitCountsResult {
+    pub max_size: usize,
+    /// Prefix of [`GRAPHLET_ORBIT_LABELS`] covered by `counts`.
+    pub orbit_labels: Vec<String>,
+    pub nodes: Vec<String>,
+    /// `counts[i][o]`: induced graphlets in which `nodes[i]` occupies orbit `o`.
+    pub counts: Vec<Vec<u64>>,
+    /// `(graphlet, induced occurrences)` over the whole graph.
+    pub graphlet_totals: Vec<(String, u64)>,
+    /// `x - y - z` neighbor paths walked.
+    pub neighbor_pairs_enumerated: usize,
+    /// Common-neighbor triples probed while counting 4-cliques.
+    pub neighbor_triples_enumerated: usize,
+    pub witness: ComplexityWitness,
+}
+
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct SquareClusteringResult {
     pub scores: Vec<CentralityScore>,
@@ -995,6 +1453,66 @@ pub struct PruferResult {
     pub edges: Vec<(usize, usize)>,
 }
 
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DisparityEdgeSignificance {
+    /// Endpoints in canonical orientation (`left <= right`).
+    pub left: String,
+    pub right: String,
+    pub weight: f64,
+    /// Disparity p-value seen from `left`; `None` when `left` has degree 1.
+    pub left_p_value: Option<f64>,
+    /// Disparity p-value seen from `right`; `None` when `right` has degree 1.
+    pub right_p_value: Option<f64>,
+    pub kept: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DisparityBackboneReport {
+    pub alpha: f64,
+    pub kept_edges: usize,
+    pub removed_edges: usize,
+    /// Per-edge significance in canonical edge order, when requested.
+    pub edge_significance: Option<Vec<DisparityEdgeSignificance>>,
+    pub witness: ComplexityWitness,
+}
+
+#[derive(Debug, Clone)]
+pub struct DisparityBackboneResult {
+    pub backbone: Graph,
+    pub report: DisparityBackboneReport,
+}
+
+/// Row/column order of a dense matrix export.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum MatrixOrdering {
+    /// Node insertion order, as `graph.nodes_ordered()`.
+    #[default]
+    GraphOrder,
+    /// Node names in ascending byte order.
+    SortedName,
+}
+
+/// Node-count cap a caller can pass to the dense matrix exports.
+pub const DEFAULT_DENSE_MATRIX_MAX_NODES: usize = 10_000;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AdjacencyMatrix {
+    /// Row (and column) labels.
+    pub labels: Vec<String>,
+    /// `labels.len()` x `labels.len()` entries, row-major.
+    pub data: Vec<f64>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct IncidenceMatrix {
+    /// Row labels, in graph node order.
+    pub labels: Vec<String>,
+    /// Column edges in canonical edge order (`left <= right`, sorted).
+    pub edges: Vec<(String, String)>,
+    /// `labels.len()` x `edges.len()` entries, row-major.
+    pub data: Vec<f64>,
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub enum FlowError {
     NodeNotFound(String),
@@ -1033,6 +1551,97 @@ impl fmt::Display for ModularityError {
 
 impl std::error::Error for ModularityError {}
 
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum WaypointRoutingError {
+    NodeNotFound(String),
+    TooManyWaypoints { count: usize, max: usize },
+}
+
+impl fmt::Display for WaypointRoutingError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NodeNotFound(node) => write!(f, "node {node} not in graph"),
+            Self::TooManyWaypoints { count, max } => write!(
+                f,
+                "unordered waypoint routing supports at most {max} waypoints, got {count}; \
+                 use ordered routing for larger sets"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for WaypointRoutingError {}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum WeightPrecisionError {
+    UnsupportedPlaces { places: u32, max: u32 },
+}
+
+impl fmt::Display for WeightPrecisionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnsupportedPlaces { places, max } => write!(
+                f,
+                "decimal weight precision supports at most {max} places, got {places}"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for WeightPrecisionError {}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum GraphletOrbitError {
+    UnsupportedSize(usize),
+}
+
+impl fmt::Display for GraphletOrbitError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnsupportedSize(size) => write!(
+                f,
+                "graphlet orbit counting supports max_size 3 or 4, got {size}"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for GraphletOrbitError {}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum MatrixError {
+    TooManyNodes { nodes: usize, max_nodes: usize },
+    NotSquare { len: usize },
+    LabelCountMismatch { labels: usize, dimension: usize },
+    DuplicateLabel(String),
+    Asymmetric { row: String, column: String },
+}
+
+impl fmt::Display for MatrixError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::TooManyNodes { nodes, max_nodes } => write!(
+                f,
+                "dense matrix export of {nodes} nodes exceeds the cap of {max_nodes}"
+            ),
+            Self::NotSquare { len } => {
+                write!(f, "matrix data of length {len} is not square")
+            }
+            Self::LabelCountMismatch { labels, dimension } => write!(
+                f,
+                "{labels} labels given for a {dimension}x{dimension} matrix"
+            ),
+            Self::DuplicateLabel(label) => write!(f, "duplicate matrix label {label}"),
+            Self::Asymmetric { row, column } => write!(
+                f,
+                "entries ({row}, {column}) and ({column}, {row}) differ; an undirected graph needs a symmetric matrix"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for MatrixError {}
+
 #[derive(Debug, Clone)]
 struct IndexedFlowResidual {
     capacities: Vec<BTreeMap<usize, f64>>,
@@ -1073,6 +1682,65 @@ pub fn shortest_path_unweighted(graph: &Graph, source: &str, target: &str) -> Sh
     }
 }
 
+/// `shortest_path_unweighted` with a selectable frontier discipline.
+///
+/// `FrontierOrder::Fifo` delegates to `shortest_path_unweighted` unchanged, so the
+/// returned path depends on neighbor insertion order. `FrontierOrder::Lexicographic`
+/// runs the priority frontier of `lexicographic_bfs_order` and stops once `target`
+/// is discovered; among all shortest paths it returns the lexicographically smallest
+/// node sequence, independent of insertion order.
+#[must_use]
+pub fn shortest_path_unweighted_with_order(
+    graph: &Graph,
+    source: &str,
+    target: &str,
+    order: FrontierOrder,
+) -> ShortestPathResult {
+    if order == FrontierOrder::Fifo {
+        return shortest_path_unweighted(graph, source, target);
+    }
+
+    let witness = |nodes_touched, edges_scanned, queue_peak| ComplexityWitness {
+        algorithm: "lexicographic_bfs_shortest_path".to_owned(),
+        complexity_claim: "O((|V| + |E|) log |V|)".to_owned(),
+        nodes_touched,
+        edges_scanned,
+        queue_peak,
+    };
+    let (Some(source_idx), Some(target_idx)) =
+        (graph.get_node_index(source), graph.get_node_index(target))
+    else {
+        return ShortestPathResult {
+            path: None,
+            witness: witness(0, 0, 0),
+        };
+    };
+
+    let search = priority_bfs_kernel(
+        graph,
+        source_idx,
+        |_| (),
+        FrontierRank::ParentVisit,
+        Some(target_idx),
+    );
+    let path = (source_idx == target_idx || search.parent[target_idx].is_some()).then(|| {
+        let nodes = graph.nodes_ordered();
+        let mut path = vec![nodes[target_idx].to_owned()];
+        let mut cursor = target_idx;
+        while let Some(parent) = search.parent[cursor] {
+            path.push(nodes[parent].to_owned());
+            cursor = parent;
+        }
+        path.reverse();
+        path
+    });
+
+    ShortestPathResult {
+        path,
+        witness: witness(search.discovered, search.edges_scanned, search.queue_peak),
+    }
+}
+
 /// Fast internal implementation using integer indices.
 fn shortest_path_unweighted_fast(graph: &Graph, source: &str, target: &str) -> Option<Vec<String>> {
     let source_idx = graph.get_node_index(source)?;
@@ -1434,6 +2102,347 @@ pub fn shortest_path_weighted(
     }
 }
 
+/// Shortest `source` -> `target` route that passes through every waypoint.
+///
+/// With `ordered` the waypoints are visited as given and each leg is an
+/// independent shortest path. Otherwise the visit order is solved exactly by a
+/// bitmask DP over the pairwise leg distances, which is only offered for up to
+/// [`MAX_UNORDERED_WAYPOINTS`] waypoints. Legs run Dijkstra on `weight_attr`
+/// (missing, non-finite or negative weights count as 1.0) or BFS when it is
+/// `None`. Equal-cost visit orders resolve to the lexicographically smallest
+/// sequence of waypoint positions.
+pub fn shortest_path_via_waypoints(
+    graph: &Graph,
+    source: &str,
+    target: &str,
+    waypoints: &[&str],
+    weight_attr: Option<&str>,
+    ordered: bool,
+) -> Result<WaypointRouteResult, WaypointRoutingError> {
+    for node in std::iter::once(&source)
+        .chain(waypoints)
+        .chain(std::iter::once(&target))
+    {
+        if !graph.has_node(node) {
+            return Err(WaypointRoutingError::NodeNotFound((*node).to_owned()));
+        }
+    }
+    let k = waypoints.len();
+    if !ordered && k > MAX_UNORDERED_WAYPOINTS {
+        return Err(WaypointRoutingError::TooManyWaypoints {
+            count: k,
+            max: MAX_UNORDERED_WAYPOINTS,
+        });
+    }
+
+    let mut witness = ComplexityWitness {
+        algorithm: "shortest_path_via_waypoints".to_owned(),
+        complexity_claim: if ordered {
+            "O(k |E| log |V|)"
+        } else {
+            "O(k^2 |E| log |V| + 2^k k^2)"
+        }
+        .to_owned(),
+        nodes_touched: 0,
+        edges_scanned: 0,
+        queue_peak: 0,
+    };
+    // Terminal indices: waypoints keep their positions, the source is `k` and
+    // the target `k + 1`.
+    let terminals: Vec<&str> = waypoints.iter().copied().chain([source, target]).collect();
+    let (source_idx, target_idx) = (k, k + 1);
+    let mut legs = HashMap::new();
+
+    let order: Vec<usize> = if ordered || k == 0 {
+        (0..k).collect()
+    } else {
+        let mut dist = vec![vec![f64::INFINITY; k + 2]; k + 2];
+        for from in (0..k).chain([source_idx]) {
+            for to in (0..k).chain([target_idx]) {
+                if from == to {
+                    continue;
+                }
+                if let Some((_, weight)) = waypoint_leg(
+                    &mut legs,
+                    graph,
+                    &terminals,
+                    (from, to),
+                    weight_attr,
+                    &mut witness,
+                ) {
+                    dist[from][to] = *weight;
+                }
+            }
+        }
+
+        // best[mask][last]: cheapest route from the source through exactly the
+        // waypoints in `mask`, ending at `last`, with its visit order.
+        let full = (1usize << k) - 1;
+        let mut best: Vec<Vec<WaypointOrderCandidate>> = vec![vec![None; k]; full + 1];
+        for first in 0..k {
+            if dist[source_idx][first].is_finite() {
+                best[1 << first][first] = Some((dist[source_idx][first], vec![first]));
+            }
+        }
+        for mask in 1..=full {
+            for last in 0..k {
+                let Some((cost, prefix)) = best[mask][last].clone() else {
+                    continue;
+                };
+                for next in 0..k {
+                    if mask & (1 << next) != 0 || !dist[last][next].is_finite() {
+                        continue;
+                    }
+                    let mut extended = prefix.clone();
+                    extended.push(next);
+                    offer_waypoint_order(
+                        &mut best[mask | (1 << next)][next],
+                        (cost + dist[last][next], extended),
+                    );
+                }
+            }
+        }
+        let mut winner = None;
+        for last in 0..k {
+            if let Some((cost, prefix)) = &best[full][last]
+                && dist[last][target_idx].is_finite()
+            {
+                offer_waypoint_order(&mut winner, (cost + dist[last][target_idx], prefix.clone()));
+            }
+        }
+        // With no feasible order, walk the given one so the failing leg is
+        // reported against the caller's own sequence.
+        winner.map_or_else(|| (0..k).collect(), |(_, order)| order)
+    };
+
+    let waypoint_order = order
+        .iter()
+        .map(|&idx| waypoints[idx].to_owned())
+        .collect::<Vec<_>>();
+    let stops = std::iter::once(source_idx)
+        .chain(order.iter().copied())
+        .chain([target_idx])
+        .collect::<Vec<_>>();
+    let mut path = vec![source.to_owned()];
+    let mut total_weight = 0.0;
+    for pair in stops.windows(2) {
+        match waypoint_leg(
+            &mut legs,
+            graph,
+            &terminals,
+            (pair[0], pair[1]),
+            weight_attr,
+            &mut witness,
+        ) {
+            Some((leg_path, weight)) => {
+                path.extend(leg_path.iter().skip(1).cloned());
+                total_weight += weight;
+            }
+            None => {
+                return Ok(WaypointRouteResult {
+                    path: None,
+                    total_weight: None,
+                    waypoint_order,
+                    unreachable_leg: Some(WaypointLeg {
+                        source: terminals[pair[0]].to_owned(),
+                        target: terminals[pair[1]].to_owned(),
+                    }),
+                    witness,
+                });
+            }
+        }
+    }
+
+    Ok(WaypointRouteResult {
+        path: Some(path),
+        total_weight: Some(total_weight),
+        waypoint_order,
+        unreachable_leg: None,
+        witness,
+    })
+}
+
+type WaypointLegCache = HashMap<(usize, usize), Option<(Vec<String>, f64)>>;
+/// Route cost paired with the waypoint positions in visit order.
+type WaypointOrderCandidate = Option<(f64, Vec<usize>)>;
+
+/// Memoized single leg of a waypoint route; every fresh search is folded into
+/// `witness`.
+fn waypoint_leg<'a>(
+    legs: &'a mut WaypointLegCache,
+    graph: &Graph,
+    terminals: &[&str],
+    (from, to): (usize, usize),
+    weight_attr: Option<&str>,
+    witness: &mut ComplexityWitness,
+) -> Option<&'a (Vec<String>, f64)> {
+    legs.entry((from, to))
+        .or_insert_with(|| {
+            let (left, right) = (terminals[from], terminals[to]);
+            let leg = match weight_attr {
+                Some(attr) => shortest_path_weighted(graph, left, right, attr),
+                None => shortest_path_unweighted(graph, left, right),
+            };
+            witness.nodes_touched += leg.witness.nodes_touched;
+            witness.edges_scanned += leg.witness.edges_scanned;
+            witness.queue_peak = witness.queue_peak.max(leg.witness.queue_peak);
+            leg.path.map(|path| {
+                let weight = match weight_attr {
+                    Some(attr) => path
+                        .windows(2)
+                        .map(|edge| edge_weight_or_default(graph, &edge[0], &edge[1], attr))
+                        .sum(),
+                    None => (path.len() - 1) as f64,
+                };
+                (path, weight)
+            })
+        })
+        .as_ref()
+}
+
+fn offer_waypoint_order(slot: &mut WaypointOrderCandidate, candidate: (f64, Vec<usize>)) {
+    let replace = match slot {
+        None => true,
+        Some((cost, order)) => {
+            candidate.0 < *cost - DISTANCE_COMPARISON_EPSILON
+                || (candidate.0 <= *cost + DISTANCE_COMPARISON_EPSILON && candidate.1 < *order)
+        }
+    };
+    if replace {
+        *slot = Some(candidate);
+    }
+}
+
+/// Widest (maximin) path: among all `source` -> `target` paths, one whose
+/// smallest edge capacity is as large as possible.
+///
+/// Dijkstra with the frontier ordered by bottleneck capacity, descending, and
+/// ties popped in node-name order; a node keeps the predecessor that first
+/// offered its final bottleneck. Capacities follow Dijkstra's weight rules
+/// (missing, non-finite or negative counts as 1.0). Missing endpoints and
+/// disconnected pairs yield `path: None`; `source == target` yields the
+/// single-node path with no bottleneck.
+#[must_use]
+pub fn widest_path(
+    graph: &Graph,
+    source: &str,
+    target: &str,
+    capacity_attr: &str,
+) -> BottleneckPathResult {
+    bottleneck_path(graph, source, target, capacity_attr, true)
+}
+
+/// Minimax path: among all `source` -> `target` paths, one whose largest edge
+/// weight is as small as possible. The dual of [`widest_path`], with the same
+/// tie-breaks, weight rules and edge cases.
+#[must_use]
+pub fn minimax_path(
+    graph: &Graph,
+    source: &str,
+    target: &str,
+    weight_attr: &str,
+) -> BottleneckPathResult {
+    bottleneck_path(graph, source, target, weight_attr, false)
+}
+
+fn bottleneck_path(
+    graph: &Graph,
+    source: &str,
+    target: &str,
+    weight_attr: &str,
+    widest: bool,
+) -> BottleneckPathResult {
+    let algorithm = if widest {
+        "widest_path"
+    } else {
+        "minimax_path"
+    };
+    let mut witness = ComplexityWitness {
+        algorithm: algorithm.to_owned(),
+        complexity_claim: "O(|E| log |V|)".to_owned(),
+        nodes_touched: 0,
+        edges_scanned: 0,
+        queue_peak: 0,
+    };
+    let (Some(source_idx), Some(target_idx)) =
+        (graph.get_node_index(source), graph.get_node_index(target))
+    else {
+        return BottleneckPathResult {
+            path: None,
+            bottleneck: None,
+            witness,
+        };
+    };
+
+    // Search on keys that are always maximized: the capacity itself for the
+    // widest path, the negated weight for the minimax path.
+    let sign = if widest { 1.0 } else { -1.0 };
+    let nodes = graph.nodes_ordered();
+    let mut best = vec![f64::NEG_INFINITY; nodes.len()];
+    let mut predecessors = vec![usize::MAX; nodes.len()];
+    let mut finalized = vec![false; nodes.len()];
+    let mut heap = BinaryHeap::new();
+    best[source_idx] = f64::INFINITY;
+    heap.push(BottleneckState {
+        key: f64::INFINITY,
+        name: nodes[source_idx],
+        node: source_idx,
+    });
+    witness.nodes_touched = 1;
+    witness.queue_peak = 1;
+
+    while let Some(BottleneckState { key, node: u, .. }) = heap.pop() {
+        if finalized[u] || key < best[u] {
+            continue;
+        }
+        finalized[u] = true;
+        if u == target_idx {
+            break;
+        }
+        for &v in graph.neighbors_indices(u).unwrap_or(&[]) {
+            witness.edges_scanned += 1;
+            if finalized[v] {
+                continue;
+            }
+            let weight = edge_weight_or_default_idx(graph, u, v, weight_attr);
+            let candidate = key.min(sign * weight);
+            if candidate > best[v] {
+                if best[v] == f64::NEG_INFINITY {
+                    witness.nodes_touched += 1;
+                }
+                best[v] = candidate;
+                predecessors[v] = u;
+                heap.push(BottleneckState {
+                    key: candidate,
+                    name: nodes[v],
+                    node: v,
+                });
+                witness.queue_peak = witness.queue_peak.max(heap.len());
+            }
+        }
+    }
+
+    if !finalized[target_idx] {
+        return BottleneckPathResult {
+            path: None,
+            bottleneck: None,
+            witness,
+        };
+    }
+    let mut path = vec![nodes[target_idx].to_owned()];
+    let mut cursor = target_idx;
+    while cursor != source_idx {
+        cursor = predecessors[cursor];
+        path.push(nodes[cursor].to_owned());
+    }
+    path.reverse();
+    BottleneckPathResult {
+        bottleneck: (source_idx != target_idx).then_some(sign * best[target_idx]),
+        path: Some(path),
+        witness,
+    }
+}
+
 #[cfg(test)]
 fn shortest_path_weighted_string_reference(
     graph: &Graph,
@@ -3208,6 +4217,171 @@ pub fn closeness_centrality_directed(graph: &DiGraph) -> ClosenessCentralityResu
     closeness_centrality_generic(graph)
 }
 
+/// Node weight under the same rules as edge weights in Dijkstra: a missing,
+/// non-numeric, non-finite or negative value counts as 1.0.
+#[must_use]
+pub fn node_weight_or_default(graph: &Graph, node: &str, weight_attr: &str) -> f64 {
+    parsed_node_weight(graph, node, weight_attr).unwrap_or(1.0)
+}
+
+fn parsed_node_weight(graph: &Graph, node: &str, weight_attr: &str) -> Option<f64> {
+    graph
+        .node_attrs(node)
+        .and_then(|attrs| attrs.get(weight_attr))
+        .and_then(CgseValue::as_f64)
+        .filter(|value| value.is_finite() && *value >= 0.0)
+}
+
+/// Weights in `nodes` order, plus the nodes that fell back to 1.0.
+fn node_weights_in_order(
+    graph: &Graph,
+    nodes: &[&str],
+    weight_attr: &str,
+) -> (Vec<f64>, Vec<String>) {
+    let mut defaulted = Vec::new();
+    let weights = nodes
+        .iter()
+        .map(|&node| {
+            parsed_node_weight(graph, node, weight_attr).unwrap_or_else(|| {
+                defaulted.push(node.to_owned());
+                1.0
+            })
+        })
+        .collect();
+    (weights, defaulted)
+}
+
+/// Degree centrality in which every neighbor counts with its node weight:
+/// `sum_{u in N(v)} w(u) / (W - w(v))`, where `W` is the total node weight.
+///
+/// A self-loop counts `w(v)` twice, as it counts twice towards the degree, and
+/// a node whose complement weighs nothing scores 0.0. With every weight at 1.0
+/// the scores are bit-identical to [`degree_centrality`].
+#[must_use]
+pub fn node_weighted_degree_centrality(
+    graph: &Graph,
+    node_weight_attr: &str,
+) -> NodeWeightedCentralityResult {
+    let nodes = graph.nodes_ordered();
+    let n = nodes.len();
+    let (weights, defaulted_weight_nodes) = node_weights_in_order(graph, &nodes, node_weight_attr);
+    let total_weight = weights.iter().sum::<f64>();
+
+    let mut edges_scanned = 0usize;
+    let mut scores = Vec::with_capacity(n);
+    for (idx, node) in nodes.iter().enumerate() {
+        let score = if n <= 1 {
+            // NetworkX returns centrality 1.0 for all nodes when n <= 1.
+            1.0
+        } else {
+            let neighbors = graph.neighbors_indices(idx).unwrap_or(&[]);
+            edges_scanned += neighbors.len();
+            let mut neighbor_weight = neighbors.iter().map(|&other| weights[other]).sum::<f64>();
+            if graph.has_edge(node, node) {
+                neighbor_weight += weights[idx];
+            }
+            let complement = total_weight - weights[idx];
+            if complement > 0.0 {
+                // Same `d * (1 / (n - 1))` shape as `degree_centrality`.
+                neighbor_weight * (1.0 / complement)
+            } else {
+                0.0
+            }
+        };
+        scores.push(CentralityScore {
+            node: (*node).to_owned(),
+            score,
+        });
+    }
+
+    NodeWeightedCentralityResult {
+        scores,
+        defaulted_weight_nodes,
+        witness: ComplexityWitness {
+            algorithm: "node_weighted_degree_centrality".to_owned(),
+            complexity_claim: "O(|V| + |E|)".to_owned(),
+            nodes_touched: n,
+            edges_scanned,
+            queue_peak: 0,
+        },
+    }
+}
+
+/// Closeness centrality in which every target counts with its node weight
+/// (e.g. a population), generalizing the Wasserman–Faust form used by
+/// [`closeness_centrality`]:
+///
+/// `C(u) = (R(u) / D(u)) * (R(u) / (W - w(u)))`
+///
+/// `R(u)` is the total weight of the other nodes reachable from `u`,
+/// `D(u) = sum_v w(v) * d(u, v)` over those nodes with hop distances `d`, and
+/// `W` is the total node weight; `C(u) = 0.0` when `R(u)` or `D(u)` is zero.
+/// With every weight at 1.0 the scores are bit-identical to
+/// [`closeness_centrality`].
+#[must_use]
+pub fn node_weighted_closeness_centrality(
+    graph: &Graph,
+    node_weight_attr: &str,
+) -> NodeWeightedCentralityResult {
+    let nodes = graph.nodes_ordered();
+    let n = nodes.len();
+    let (weights, defaulted_weight_nodes) = node_weights_in_order(graph, &nodes, node_weight_attr);
+    let total_weight = weights.iter().sum::<f64>();
+
+    let mut distance = vec![-1i64; n];
+    let mut queue = VecDeque::new();
+    let mut edges_scanned = 0usize;
+    let mut queue_peak = 0usize;
+    let mut scores = Vec::with_capacity(n);
+    for (source, node) in nodes.iter().enumerate() {
+        distance.fill(-1);
+        distance[source] = 0;
+        queue.push_back(source);
+        queue_peak = queue_peak.max(queue.len());
+        let mut reached_weight = 0.0_f64;
+        let mut weighted_distance = 0.0_f64;
+        while let Some(v) = queue.pop_front() {
+            let d = distance[v];
+            if v != source {
+                reached_weight += weights[v];
+                weighted_distance += weights[v] * (d as f64);
+            }
+            for &w in graph.neighbors_indices(v).unwrap_or(&[]) {
+                edges_scanned += 1;
+                if distance[w] < 0 {
+                    distance[w] = d + 1;
+                    queue.push_back(w);
+                    queue_peak = queue_peak.max(queue.len());
+                }
+            }
+        }
+
+        let score = if reached_weight > 0.0 && weighted_distance > 0.0 {
+            let mut closeness = reached_weight / weighted_distance;
+            closeness *= reached_weight / (total_weight - weights[source]);
+            closeness
+        } else {
+            0.0
+        };
+        scores.push(CentralityScore {
+            node: (*node).to_owned(),
+            score,
+        });
+    }
+
+    NodeWeightedCentralityResult {
+        scores,
+        defaulted_weight_nodes,
+        witness: ComplexityWitness {
+            algorithm: "node_weighted_closeness_centrality".to_owned(),
+            complexity_claim: "O(|V| * (|V| + |E|))".to_owned(),
+            nodes_touched: n,
+            edges_scanned,
+            queue_peak,
+        },
+    }
+}
+
 /// Reusable per-worker scratch for one reverse-BFS pass (closeness/harmonic).
 /// `distance` uses `-1` as the unreached sentinel and is cleared per source.
 struct CentralityBfsScratch {
@@ -7611,6 +8785,8 @@ pub fn max_weight_matching(
         return WeightedMatchingResult {
             matching: Vec::new(),
             total_weight: 0.0,
+            total_weight_exact: None,
+            weight_diagnostics: Vec::new(),
             witness: ComplexityWitness {
                 algorithm: if maxcardinality {
                     "blossom_max_weight_matching_maxcardinality".to_owned()
@@ -7641,6 +8817,8 @@ pub fn max_weight_matching(
     WeightedMatchingResult {
         matching,
         total_weight,
+        total_weight_exact: None,
+        weight_diagnostics: Vec::new(),
         witness: ComplexityWitness {
             algorithm: if maxcardinality {
                 "blossom_max_weight_matching_maxcardinality".to_owned()
@@ -7669,6 +8847,8 @@ pub fn min_weight_matching(graph: &Graph, weight_attr: &str) -> WeightedMatching
         return WeightedMatchingResult {
             matching: Vec::new(),
             total_weight: 0.0,
+            total_weight_exact: None,
+            weight_diagnostics: Vec::new(),
             witness: ComplexityWitness {
                 algorithm: "blossom_min_weight_matching".to_owned(),
                 complexity_claim: "O(|V|^3)".to_owned(),
@@ -7710,6 +8890,8 @@ pub fn min_weight_matching(graph: &Graph, weight_attr: &str) -> WeightedMatching
     WeightedMatchingResult {
         matching,
         total_weight,
+        total_weight_exact: None,
+        weight_diagnostics: Vec::new(),
         witness: ComplexityWitness {
             algorithm: "blossom_min_weight_matching".to_owned(),
             complexity_claim: "O(|V|^3)".to_owned(),
@@ -7720,6 +8902,508 @@ pub fn min_weight_matching(graph: &Graph, weight_attr: &str) -> WeightedMatching
     }
 }
 
+/// `max_weight_matching` with an optional exact decimal total.
+///
+/// The blossom selection is identical to `max_weight_matching`; only the reported
+/// total gains `total_weight_exact` (and per-edge `weight_diagnostics`) when
+/// `precision` is `WeightPrecision::Decimal`.
+///
+/// # Errors
+///
+/// Returns [`WeightPrecisionError::UnsupportedPlaces`] when `precision` asks for
+/// more than [`MAX_DECIMAL_WEIGHT_PLACES`] places.
+pub fn max_weight_matching_with_precision(
+    graph: &Graph,
+    maxcardinality: bool,
+    weight_attr: &str,
+    precision: WeightPrecision,
+) -> Result<WeightedMatchingResult, WeightPrecisionError> {
+    let places = precision.decimal_places()?;
+    let mut result = max_weight_matching(graph, maxcardinality, weight_attr);
+    attach_exact_matching_total(graph, weight_attr, places, &mut result);
+    Ok(result)
+}
+
+/// `min_weight_matching` with an optional exact decimal total; see
+/// [`max_weight_matching_with_precision`].
+///
+/// # Errors
+///
+/// Returns [`WeightPrecisionError::UnsupportedPlaces`] when `precision` asks for
+/// more than [`MAX_DECIMAL_WEIGHT_PLACES`] places.
+pub fn min_weight_matching_with_precision(
+    graph: &Graph,
+    weight_attr: &str,
+    precision: WeightPrecision,
+) -> Result<WeightedMatchingResult, WeightPrecisionError> {
+    let places = precision.decimal_places()?;
+    let mut result = min_weight_matching(graph, weight_attr);
+    attach_exact_matching_total(graph, weight_attr, places, &mut result);
+    Ok(result)
+}
+
+fn attach_exact_matching_total(
+    graph: &Graph,
+    weight_attr: &str,
+    places: Option<u32>,
+    result: &mut WeightedMatchingResult,
+) {
+    let Some(places) = places else {
+        return;
+    };
+    // Same defaulting as `matching_edge_weight_or_default`: any finite value counts.
+    let mut sum = DecimalWeightSum::new(places);
+    for (left, right) in &result.matching {
+        let raw = graph
+            .edge_attrs(left, right)
+            .and_then(|attrs| attrs.get(weight_attr));
+        sum.add_edge(left, right, raw, f64::is_finite);
+    }
+    (result.total_weight_exact, result.weight_diagnostics) = sum.finish();
+}
+
+/// Minimum- or maximum-weight edge subset whose degree at every node lies in
+/// `[min, max]`, read from the `min_degree_attr` / `max_degree_attr` node
+/// attributes. Bounds must be finite and >= 0 (min rounds up, max rounds down);
+/// missing or invalid bounds default to 0 and unbounded. Edge weights follow
+/// `max_weight_matching` (any finite value, else 1.0), and self-loops are never
+/// selected.
+///
+/// Each connected component is solved on its own; one with no binding bound
+/// simply keeps every edge that improves the objective. The others reduce to
+/// perfect matching on Tutte's gadget: a node of degree `d` splits into one port
+/// per incident edge plus `d - max` mandatory and `max - min` optional
+/// absorbers, each adjacent to all of its ports. Matching an edge's two ports
+/// selects it; a port matched to an absorber drops it. Leftover optional
+/// absorbers pair along a path, and a per-node parity vertex takes the odd one
+/// out or joins a two-vertex-per-node chain that pairs parity vertices across
+/// nodes, so the gadget has `O(|E|)` vertices and `O(|E| * d_max)` edges. It has
+/// a perfect matching exactly when the bounds are satisfiable, so one
+/// maximum-cardinality blossom run per component either yields the optimum or
+/// proves infeasibility; in the latter case the result still carries the
+/// selection read off that closest matching, and `violated_node` names the first
+/// node whose bounds it misses.
+#[must_use]
+pub fn degree_constrained_subgraph(
+    graph: &Graph,
+    weight_attr: &str,
+    min_degree_attr: &str,
+    max_degree_attr: &str,
+    objective: DegreeConstrainedObjective,
+) -> DegreeConstrainedSubgraphResult {
+    let candidates = weighted_edge_candidates(graph, weight_attr)
+        .into_iter()
+        .filter(|edge| edge.left != edge.right)
+        .collect::<Vec<WeightedEdgeCandidate>>();
+    let bounds = |node: &str| {
+        let min = parsed_node_weight(graph, node, min_degree_attr)
+            .map_or(0, |value| value.ceil() as usize);
+        let max = parsed_node_weight(graph, node, max_degree_attr)
+            .map_or(usize::MAX, |value| value.floor() as usize);
+        (min, max)
+    };
+
+    // Solving components separately keeps each gadget, and therefore each
+    // blossom weight scale, sized to that component alone.
+    let (components, _, _, _) = connected_components_borrowed(graph);
+    let component_of = components
+        .iter()
+        .enumerate()
+        .flat_map(|(index, nodes)| nodes.iter().map(move |&node| (node, index)))
+        .collect::<HashMap<&str, usize>>();
+    let mut component_edges = vec![Vec::<&WeightedEdgeCandidate>::new(); components.len()];
+    for edge in &candidates {
+        component_edges[component_of[edge.left.as_str()]].push(edge);
+    }
+
+    let mut selected = Vec::<&WeightedEdgeCandidate>::new();
+    let mut gadget_edges = 0_usize;
+    for edges in &component_edges {
+        let (chosen, scanned) = degree_constrained_component(edges, &bounds, objective);
+        selected.extend(chosen.into_iter().map(|k| edges[k]));
+        gadget_edges += scanned;
+    }
+    selected.sort_unstable_by(|a, b| (&a.left, &a.right).cmp(&(&b.left, &b.right)));
+
+    let mut selected_edges = Vec::<(String, String)>::new();
+    let mut total_weight = 0.0_f64;
+    let mut selected_degree = HashMap::<&str, usize>::new();
+    for edge in selected {
+        selected_edges.push((edge.left.clone(), edge.right.clone()));
+        total_weight += edge.weight;
+        *selected_degree.entry(edge.left.as_str()).or_default() += 1;
+        *selected_degree.entry(edge.right.as_str()).or_default() += 1;
+    }
+    let degrees = graph
+        .nodes_ordered()
+        .into_iter()
+        .map(|node| {
+            (
+                node.to_owned(),
+                selected_degree.get(node).copied().unwrap_or(0),
+            )
+        })
+        .collect::<Vec<(String, usize)>>();
+    let violated_node = degrees
+        .iter()
+        .find(|(node, degree)| {
+            let (min, max) = bounds(node);
+            *degree < min || *degree > max
+        })
+        .map(|(node, _)| node.clone());
+
+    DegreeConstrainedSubgraphResult {
+        selected_edges,
+        total_weight,
+        degrees,
+        feasible: violated_node.is_none(),
+        violated_node,
+        witness: ComplexityWitness {
+            algorithm: "blossom_degree_constrained_subgraph".to_owned(),
+            complexity_claim: "O(|E| * d_max + |E|^3)".to_owned(),
+            nodes_touched: graph.node_count(),
+            edges_scanned: gadget_edges,
+            queue_peak: 0,
+        },
+    }
+}
+
+/// Solves one connected component of `degree_constrained_subgraph`.
+///
+/// Returns the indices of the selected `edges` and the gadget edge count.
+fn degree_constrained_component(
+    edges: &[&WeightedEdgeCandidate],
+    bounds: &impl Fn(&str) -> (usize, usize),
+    objective: DegreeConstrainedObjective,
+) -> (Vec<usize>, usize) {
+    let gains = edges
+        .iter()
+        .map(|edge| match objective {
+            DegreeConstrainedObjective::MinimizeWeight => -edge.weight,
+            DegreeConstrainedObjective::MaximizeWeight => edge.weight,
+        })
+        .collect::<Vec<f64>>();
+    // Ports `2k` and `2k + 1` are edge `k`'s ends at `left` and `right`.
+    let mut ports = HashMap::<&str, Vec<usize>>::new();
+    for (k, edge) in edges.iter().enumerate() {
+        ports.entry(edge.left.as_str()).or_default().push(2 * k);
+        ports
+            .entry(edge.right.as_str())
+            .or_default()
+            .push(2 * k + 1);
+    }
+    let mut gadget_nodes = ports
+        .iter()
+        .map(|(&node, node_ports)| {
+            let degree = node_ports.len();
+            let (min, max) = bounds(node);
+            let max = max.min(degree);
+            (node, min.min(max), max)
+        })
+        .collect::<Vec<(&str, usize, usize)>>();
+    let binding = gadget_nodes
+        .iter()
+        .any(|&(node, min, max)| min > 0 || max < ports[node].len());
+    if !binding {
+        let chosen = (0..edges.len()).filter(|&k| gains[k] > 0.0).collect();
+        return (chosen, 0);
+    }
+    gadget_nodes.sort_unstable();
+
+    // Every perfect matching has the same size, so lifting all gadget edges by
+    // `base` keeps the optimum while making every weight positive.
+    let base = edges
+        .iter()
+        .map(|edge| edge.weight.abs())
+        .fold(0.0_f64, f64::max)
+        + 1.0;
+    let mut gadget = gains
+        .iter()
+        .enumerate()
+        .map(|(k, gain)| (2 * k, 2 * k + 1, base + gain))
+        .collect::<Vec<(usize, usize, f64)>>();
+    let mut vertex_count = 2 * edges.len();
+    let mut parity_vertices = Vec::<usize>::new();
+    let mut absorber_total = 0_usize;
+    for (node, min, max) in gadget_nodes {
+        let node_ports = &ports[node];
+        let degree = node_ports.len();
+        let mut previous_optional = None;
+        for absorber in 0..degree - min {
+            let vertex = vertex_count;
+            vertex_count += 1;
+            gadget.extend(node_ports.iter().map(|&port| (port, vertex, base)));
+            if absorber < degree - max {
+                continue;
+            }
+            // Optional absorbers are interchangeable, so leftovers can always
+            // be taken as a prefix of this path and paired along it.
+            match previous_optional {
+                Some(previous) => gadget.push((previous, vertex, base)),
+                None => {
+                    let parity = vertex_count;
+                    vertex_count += 1;
+                    gadget.push((vertex, parity, base));
+                    parity_vertices.push(parity);
+                }
+            }
+            previous_optional = Some(vertex);
+        }
+        absorber_total += degree - min;
+    }
+    // A parity vertex stays local when its node leaves an odd number of
+    // optional absorbers and joins the chain otherwise. Leftovers total
+    // `absorber_total` modulo 2, so the chain receives an even count once a
+    // padding vertex covers the odd case.
+    if (parity_vertices.len() + absorber_total) % 2 == 1 {
+        parity_vertices.push(vertex_count);
+        vertex_count += 1;
+    }
+    // Parity vertex `i` reaches both links of chain pair `i`; the pairs form one
+    // path, and any even subset of parity vertices can claim links so the rest
+    // of the path still pairs up.
+    let mut previous_link = None;
+    for parity in parity_vertices {
+        let (first, second) = (vertex_count, vertex_count + 1);
+        vertex_count += 2;
+        gadget.extend([
+            (parity, first, base),
+            (parity, second, base),
+            (first, second, base),
+        ]);
+        if let Some(previous) = previous_link {
+            gadget.push((previous, first, base));
+        }
+        previous_link = Some(second);
+    }
+
+    let scale = blossom_bounded_weight_scale(2.0 * base, vertex_count);
+    let mut solver = BlossomMatching::new(
+        gadget
+            .iter()
+            .map(|&(u, v, weight)| (u, v, blossom_quantized_weight(weight, scale)))
+            .collect(),
+    );
+    solver.max_cardinality();
+    let mates = solver.solve();
+    let chosen = (0..edges.len())
+        .filter(|&k| mates.get(2 * k) == Some(&(2 * k + 1)))
+        .collect();
+    (chosen, gadget.len())
+}
+
+/// `shortest_path_weighted` plus the cost of the returned path.
+///
+/// `total_weight` is the f64 sum of the path's edge weights in path order;
+/// `total_weight_exact` is populated when `precision` is `WeightPrecision::Decimal`
+/// and every edge weight fits. The path itself is identical to `shortest_path_weighted`.
+/// Only this single-pair path API gains an exact total; the other weighted
+/// shortest-path APIs (`dijkstra_path_length`, `single_source_dijkstra_full`, ...)
+/// report f64 distances only.
+///
+/// # Errors
+///
+/// Returns [`WeightPrecisionError::UnsupportedPlaces`] when `precision` asks for
+/// more than [`MAX_DECIMAL_WEIGHT_PLACES`] places.
+pub fn shortest_path_weighted_with_precision(
+    graph: &Graph,
+    source: &str,
+    target: &str,
+    weight_attr: &str,
+    precision: WeightPrecision,
+) -> Result<WeightedPathCostResult, WeightPrecisionError> {
+    let places = precision.decimal_places()?;
+    let ShortestPathResult { path, witness } =
+        shortest_path_weighted(graph, source, target, weight_attr);
+    let Some(nodes) = path.as_ref() else {
+        return Ok(WeightedPathCostResult {
+            path,
+            total_weight: None,
+            total_weight_exact: None,
+            weight_diagnostics: Vec::new(),
+            witness,
+        });
+    };
+
+    let total_weight = nodes
+        .windows(2)
+        .map(|pair| edge_weight_or_default(graph, &pair[0], &pair[1], weight_attr))
+        .sum::<f64>();
+    let (total_weight_exact, weight_diagnostics) = match places {
+        None => (None, Vec::new()),
+        Some(places) => {
+            // Same defaulting as `edge_weight_or_default`: finite and non-negative.
+            let mut sum = DecimalWeightSum::new(places);
+            for pair in nodes.windows(2) {
+                let raw = graph
+                    .edge_attrs(&pair[0], &pair[1])
+                    .and_then(|attrs| attrs.get(weight_attr));
+                sum.add_edge(&pair[0], &pair[1], raw, |value| {
+                    value.is_finite() && value >= 0.0
+                });
+            }
+            sum.finish()
+        }
+    };
+
+    Ok(WeightedPathCostResult {
+        path,
+        total_weight: Some(total_weight),
+        total_weight_exact,
+        weight_diagnostics,
+        witness,
+    })
+}
+
+/// Exact fixed-point accumulator behind `WeightPrecision::Decimal`: an `i128`
+/// scaled by `10^places`. The first diagnostic poisons the total, but later edges
+/// are still scanned so every offending weight is reported.
+struct DecimalWeightSum {
+    places: u32,
+    total: Option<i128>,
+    diagnostics: Vec<WeightPrecisionDiagnostic>,
+}
+
+impl DecimalWeightSum {
+    fn new(places: u32) -> Self {
+        Self {
+            places,
+            total: Some(0),
+            diagnostics: Vec::new(),
+        }
+    }
+
+    /// Adds one edge weight. `accepts` is the f64 filter of the matching float
+    /// accumulator: values it rejects (and missing values) count as the default 1.
+    fn add_edge(
+        &mut self,
+        left: &str,
+        right: &str,
+        raw: Option<&CgseValue>,
+        accepts: impl Fn(f64) -> bool,
+    ) {
+        let text = raw
+            .filter(|value| value.as_f64().is_some_and(&accepts))
+            .map_or_else(|| "1".to_owned(), decimal_weight_text);
+        let added =
+            parse_decimal_weight_scaled(&text, self.places).and_then(|scaled| match self.total {
+                Some(total) => total
+                    .checked_add(scaled)
+                    .map(Some)
+                    .ok_or(WeightPrecisionIssue::Overflow),
+                None => Ok(None),
+            });
+        match added {
+            Ok(total) => self.total = total,
+            Err(issue) => {
+                self.total = None;
+                self.diagnostics.push(WeightPrecisionDiagnostic {
+                    left: left.to_owned(),
+                    right: right.to_owned(),
+                    raw_value: text,
+                    issue,
+                });
+            }
+        }
+    }
+
+    fn finish(self) -> (Option<String>, Vec<WeightPrecisionDiagnostic>) {
+        let exact = self
+            .total
+            .map(|total| format_decimal_weight(total, self.places));
+        (exact, self.diagnostics)
+    }
+}
+
+/// Decimal text of an attribute value. Floats use Rust's shortest round-trip
+/// representation, so `0.1_f64` reads back as `"0.1"`.
+fn decimal_weight_text(value: &CgseValue) -> String {
+    match value {
+        CgseValue::String(text) => text.trim().to_owned(),
+        CgseValue::Int(value) => value.to_string(),
+        CgseValue::Float(value) => value.to_string(),
+        CgseValue::Bool(value) => if *value { "1" } else { "0" }.to_owned(),
+        CgseValue::Map(_) => "1".to_owned(),
+    }
+}
+
+/// Parses `[+-]digits[.digits][(e|E)[+-]digits]` into `value * 10^places`.
+/// Callers only pass text whose f64 parse is finite, so the grammar is the
+/// finite subset of Rust's float syntax.
+fn parse_decimal_weight_scaled(text: &str, places: u32) -> Result<i128, WeightPrecisionIssue> {
+    let (negative, unsigned) = match text.as_bytes().first() {
+        Some(b'-') => (true, &text[1..]),
+        Some(b'+') => (false, &text[1..]),
+        _ => (false, text),
+    };
+    let (mantissa, exponent) = match unsigned.find(['e', 'E']) {
+        Some(split) => {
+            let digits = &unsigned[split + 1..];
+            let exponent = digits.parse::<i64>().unwrap_or(if digits.starts_with('-') {
+                i64::MIN / 2
+            } else {
+                i64::MAX / 2
+            });
+            (&unsigned[..split], exponent)
+        }
+        None => (unsigned, 0),
+    };
+    let (integer_digits, fraction_digits) = mantissa.split_once('.').unwrap_or((mantissa, ""));
+    let digits = format!("{integer_digits}{fraction_digits}");
+    let digits = digits.trim_start_matches('0');
+    if digits.is_empty() {
+        return Ok(0);
+    }
+
+    let shift = i64::from(places)
+        .saturating_add(exponent)
+        .saturating_sub(fraction_digits.len() as i64);
+    let kept = if shift < 0 {
+        let dropped = usize::try_from(shift.unsigned_abs()).unwrap_or(usize::MAX);
+        if dropped >= digits.len() {
+            return Err(WeightPrecisionIssue::PrecisionLoss);
+        }
+        let (kept, dropped) = digits.split_at(digits.len() - dropped);
+        if dropped.bytes().any(|digit| digit != b'0') {
+            return Err(WeightPrecisionIssue::PrecisionLoss);
+        }
+        kept
+    } else {
+        digits
+    };
+
+    let mut magnitude = 0_i128;
+    for digit in kept.bytes() {
+        magnitude = magnitude
+            .checked_mul(10)
+            .and_then(|value| value.checked_add(i128::from(digit - b'0')))
+            .ok_or(WeightPrecisionIssue::Overflow)?;
+    }
+    if shift > 0 {
+        let factor = u32::try_from(shift)
+            .ok()
+            .and_then(|shift| 10_i128.checked_pow(shift))
+            .ok_or(WeightPrecisionIssue::Overflow)?;
+        magnitude = magnitude
+            .checked_mul(factor)
+            .ok_or(WeightPrecisionIssue::Overflow)?;
+    }
+    Ok(if negative { -magnitude } else { magnitude })
+}
+
+/// Renders a scaled total without trailing fractional zeros (`"0.3"`, `"12"`).
+fn format_decimal_weight(scaled: i128, places: u32) -> String {
+    let sign = if scaled < 0 { "-" } else { "" };
+    let magnitude = scaled.unsigned_abs();
+    let divisor = 10_u128.pow(places);
+    let (integer, fraction) = (magnitude / divisor, magnitude % divisor);
+    if fraction == 0 {
+        return format!("{sign}{integer}");
+    }
+    let fraction = format!("{fraction:0width$}", width = places as usize);
+    format!("{sign}{integer}.{}", fraction.trim_end_matches('0'))
+}
+
 pub fn max_flow_edmonds_karp(
     graph: &Graph,
     source: &str,
@@ -9364,6 +11048,15 @@ fn blossom_integer_weight_scale(candidates: &[WeightedEdgeCandidate]) -> f64 {
         .iter()
         .map(|edge| edge.weight.abs())
         .fold(0.0_f64, f64::max);
+    let endpoint_count = candidates
+        .iter()
+        .flat_map(|edge| [edge.left.as_str(), edge.right.as_str()])
+        .collect::<std::collections::HashSet<_>>()
+        .len();
+    blossom_bounded_weight_scale(max_abs_weight, endpoint_count)
+}
+
+fn blossom_bounded_weight_scale(max_abs_weight: f64, endpoint_count: usize) -> f64 {
     if !max_abs_weight.is_finite() || max_abs_weight <= 0.0 {
         return 1.0;
     }
@@ -9378,12 +11071,7 @@ fn blossom_integer_weight_scale(candidates: &[WeightedEdgeCandidate]) -> f64 {
     // crash-a653763f triggered the overflow at line 691 with n=30 and
     // i32::MAX/4 per-edge scale; tightening to /(4n) leaves room for
     // the slack accumulator to grow without wrapping.
-    let endpoint_count = candidates
-        .iter()
-        .flat_map(|edge| [edge.left.as_str(), edge.right.as_str()])
-        .collect::<std::collections::HashSet<_>>()
-        .len()
-        .max(1);
+    let endpoint_count = endpoint_count.max(1);
     let preferred_scale = 1_000_000.0_f64;
     let safety_ceiling = f64::from(i32::MAX) / (4.0 * endpoint_count as f64);
     let bounded_scale = (safety_ceiling / max_abs_weight).floor().max(1.0);
@@ -14367,6 +16055,211 @@ fn triangles_counts_orig_hashset(graph: &Graph) -> Vec<usize> {
     tri_count
 }
 
+/// Per-node graphlet orbit counts for connected induced subgraphs of up to
+/// `max_size` (3 or 4) nodes.
+///
+/// Nothing is enumerated beyond 4-cliques: every other size-4 orbit falls out
+/// of an Orca-style triangular system that relates it to non-induced counts
+/// built from degrees, per-edge triangle counts and common-neighbor counts,
+/// e.g. `sum_y C(t(x,y), 2) = o13 + 3 o14`. Self-loops are ignored. The witness
+/// reports `n` nodes touched and the neighbor pairs plus triples enumerated as
+/// edges scanned.
+pub fn graphlet_orbit_counts(
+    graph: &Graph,
+    max_size: usize,
+) -> Result<GraphletOrbitCountsResult, GraphletOrbitError> {
+    if !(3..=4).contains(&max_size) {
+        return Err(GraphletOrbitError::UnsupportedSize(max_size));
+    }
+    let nodes = graph.nodes_ordered();
+    let n = nodes.len();
+    // Sorted so that edge lookups can binary search their row.
+    let adjacency = (0..n)
+        .map(|u| {
+            let mut row = graph
+                .neighbors_indices(u)
+                .unwrap_or(&[])
+                .iter()
+                .copied()
+                .filter(|&v| v != u)
+                .collect::<Vec<_>>();
+            row.sort_unstable();
+            row.dedup();
+            row
+        })
+        .collect::<Vec<_>>();
+    let degree = adjacency
+        .iter()
+        .map(|row| row.len() as u64)
+        .collect::<Vec<_>>();
+    let choose2 = |k: u64| k * k.saturating_sub(1) / 2;
+    let mut pairs = 0usize;
+    let mut triples = 0usize;
+
+    // edge_triangles[u][i]: triangles through the edge (u, adjacency[u][i]).
+    let mut marked = vec![false; n];
+    let mut edge_triangles = Vec::with_capacity(n);
+    for u in 0..n {
+        for &v in &adjacency[u] {
+            marked[v] = true;
+        }
+        let row = adjacency[u]
+            .iter()
+            .map(|&v| {
+                pairs += adjacency[v].len();
+                adjacency[v].iter().filter(|&&w| marked[w]).count() as u64
+            })
+            .collect::<Vec<_>>();
+        edge_triangles.push(row);
+        for &v in &adjacency[u] {
+            marked[v] = false;
+        }
+    }
+    let node_triangles = edge_triangles
+        .iter()
+        .map(|row| row.iter().sum::<u64>() / 2)
+        .collect::<Vec<_>>();
+
+    let orbit_count = if max_size == 3 { 4 } else { 15 };
+    let mut counts = vec![vec![0u64; orbit_count]; n];
+    let mut common = vec![0u64; n];
+    let mut common_touched = Vec::new();
+    for x in 0..n {
+        let (d, t) = (degree[x], node_triangles[x]);
+        let orbits = &mut counts[x];
+        orbits[0] = d;
+        orbits[1] = adjacency[x].iter().map(|&y| degree[y] - 1).sum::<u64>() - 2 * t;
+        orbits[2] = choose2(d) - t;
+        orbits[3] = t;
+        if max_size == 3 {
+            continue;
+        }
+
+        for &y in &adjacency[x] {
+            marked[y] = true;
+        }
+        // Non-induced counts, each named after the pattern it tallies at x.
+        let mut walks3 = 0u64;
+        for &y in &adjacency[x] {
+            for &z in &adjacency[y] {
+                if z == x {
+                    continue;
+                }
+                pairs += 1;
+                if common[z] == 0 {
+                    common_touched.push(z);
+                }
+                common[z] += 1;
+                walks3 += degree[z] - 1;
+            }
+        }
+        let mut squares = 0u64;
+        for z in common_touched.drain(..) {
+            squares += choose2(common[z]);
+            common[z] = 0;
+        }
+        let mut hub_pairs = 0u64;
+        let mut leaf_stars = 0u64;
+        let mut mid_paths = 0u64;
+        let mut tails = 0u64;
+        for (&y, &t_xy) in adjacency[x].iter().zip(&edge_triangles[x]) {
+            hub_pairs += choose2(t_xy);
+            leaf_stars += choose2(degree[y] - 1);
+            mid_paths += (d - 1) * (degree[y] - 1);
+            tails += node_triangles[y] - t_xy;
+        }
+        let mut far_triangles = 0u64;
+        let mut triangle_exits = 0u64;
+        let mut cliques = 0u64;
+        for &y in &adjacency[x] {
+            for (&z, &t_yz) in adjacency[y].iter().zip(&edge_triangles[y]) {
+                if z <= y || !marked[z] {
+                    continue;
+                }
+                far_triangles += t_yz - 1;
+                triangle_exits += degree[y] + degree[z] - 4;
+                for &w in &adjacency[z] {
+                    if w > z && marked[w] {
+                        triples += 1;
+                        if adjacency[y].binary_search(&w).is_ok() {
+                            cliques += 1;
+                        }
+                    }
+                }
+            }
+        }
+        for &y in &adjacency[x] {
+            marked[y] = false;
+        }
+
+        // Solve from the densest orbit down; every right-hand side only uses
+        // orbits already fixed.
+        let o14 = cliques;
+        let o13 = hub_pairs - 3 * o14;
+        let o12 = far_triangles - 3 * o14;
+        let o11 = t * d.saturating_sub(2) - 2 * o13 - 3 * o14;
+        let o10 = triangle_exits - 2 * o12 - 2 * o13 - 6 * o14;
+        let o9 = tails - 2 * o12 - 3 * o14;
+        let o8 = squares - o12 - o13 - 3 * o14;
+        let o7 = d * d.saturating_sub(1) * d.saturating_sub(2) / 6 - o11 - o13 - o14;
+        let o6 = leaf_stars - o9 - o10 - 2 * o12 - o13 - 3 * o14;
+        let o5 = mid_paths - 2 * t - 2 * o8 - o10 - 2 * o11 - 2 * o12 - 4 * o13 - 6 * o14;
+        let o4 = walks3 - 2 * t - 2 * o8 - 2 * o9 - o10 - 4 * o12 - 2 * o13 - 6 * o14;
+        orbits[4..].copy_from_slice(&[o4, o5, o6, o7, o8, o9, o10, o11, o12, o13, o14]);
+    }
+
+    // Each graphlet's total, read off one orbit and divided by how many of its
+    // nodes share that orbit.
+    let orbit_sum = |orbit: usize| counts.iter().map(|row| row[orbit]).sum::<u64>();
+    let graphlet_orbits: &[(usize, u64)] = if max_size == 3 {
+        &[(0, 2), (2, 1), (3, 3)]
+    } else {
+        &[
+            (0, 2),
+            (2, 1),
+            (3, 3),
+            (4, 2),
+            (7, 1),
+            (8, 4),
+            (11, 1),
+            (13, 2),
+            (14, 4),
+        ]
+    };
+    let graphlet_totals = GRAPHLET_LABELS
+        .iter()
+        .zip(graphlet_orbits)
+        .map(|(label, &(orbit, multiplicity))| {
+            ((*label).to_owned(), orbit_sum(orbit) / multiplicity)
+        })
+        .collect();
+
+    Ok(GraphletOrbitCountsResult {
+        max_size,
+        orbit_labels: GRAPHLET_ORBIT_LABELS[..orbit_count]
+            .iter()
+            .map(|label| (*label).to_owned())
+            .collect(),
+        nodes: nodes.iter().map(|node| (*node).to_owned()).collect(),
+        counts,
+        graphlet_totals,
+        neighbor_pairs_enumerated: pairs,
+        neighbor_triples_enumerated: triples,
+        witness: ComplexityWitness {
+            algorithm: "graphlet_orbit_counts".to_owned(),
+            complexity_claim: if max_size == 3 {
+                "O(|V| * deg^2)"
+            } else {
+                "O(|V| * deg^3)"
+            }
+            .to_owned(),
+            nodes_touched: n,
+            edges_scanned: pairs + triples,
+            queue_peak: 0,
+        },
+    })
+}
+
 /// Computes the square clustering coefficient for each node.
 ///
 /// The square clustering of a node `v` is the fraction of possible squares
@@ -21899,6 +23792,171 @@ pub fn bfs_layers(graph: &Graph, source: &str) -> Vec<Vec<String>> {
     layers
 }
 
+/// Breadth-first visit order with a caller-controlled frontier.
+///
+/// The frontier is a priority queue keyed by `(depth, priority(node), node name)`:
+/// layers are visited in distance order and each layer is ordered by `priority`,
+/// with the node name breaking ties. A node's parent is the first visited neighbor
+/// that discovers it. `priority` is evaluated once per discovered node. The result
+/// does not depend on edge insertion order. An unknown `source` yields an empty
+/// result.
+#[must_use]
+pub fn priority_bfs<K: Ord>(
+    graph: &Graph,
+    source: &str,
+    priority: impl Fn(&str) -> K,
+) -> PriorityBfsResult {
+    priority_bfs_result(graph, source, priority, FrontierRank::Depth, "priority_bfs")
+}
+
+/// Breadth-first visit order with a frontier keyed by `(parent visit rank, node
+/// name)`: children of an earlier-visited parent come first and siblings are
+/// visited by name. A node's parent is the first visited neighbor that discovers
+/// it, so every parent chain is the lexicographically smallest shortest path to
+/// its node.
+///
+/// This is not the Rose–Tarjan–Lueker LexBFS used for chordality testing.
+#[must_use]
+pub fn lexicographic_bfs_order(graph: &Graph, source: &str) -> PriorityBfsResult {
+    priority_bfs_result(
+        graph,
+        source,
+        |_| (),
+        FrontierRank::ParentVisit,
+        "lexicographic_bfs",
+    )
+}
+
+fn priority_bfs_result<K: Ord>(
+    graph: &Graph,
+    source: &str,
+    priority: impl Fn(&str) -> K,
+    rank_by: FrontierRank,
+    algorithm: &str,
+) -> PriorityBfsResult {
+    let witness = |nodes_touched, edges_scanned, queue_peak| ComplexityWitness {
+        algorithm: algorithm.to_owned(),
+        complexity_claim: "O((|V| + |E|) log |V|)".to_owned(),
+        nodes_touched,
+        edges_scanned,
+        queue_peak,
+    };
+    let Some(source_idx) = graph.get_node_index(source) else {
+        return PriorityBfsResult {
+            order: Vec::new(),
+            parents: Vec::new(),
+            witness: witness(0, 0, 0),
+        };
+    };
+
+    let search = priority_bfs_kernel(graph, source_idx, priority, rank_by, None);
+    let nodes = graph.nodes_ordered();
+    let parents = search
+        .order
+        .iter()
+        .map(|&index| {
+            (
+                nodes[index].to_owned(),
+                search.parent[index].map(|parent| nodes[parent].to_owned()),
+            )
+        })
+        .collect();
+    PriorityBfsResult {
+        order: search
+            .order
+            .iter()
+            .map(|&index| nodes[index].to_owned())
+            .collect(),
+        parents,
+        witness: witness(search.discovered, search.edges_scanned, search.queue_peak),
+    }
+}
+
+/// What `priority_bfs_kernel` orders the frontier by before the caller's key.
+#[derive(Debug, Clone, Copy)]
+enum FrontierRank {
+    /// Visit rank of the discovering parent; keeps parent chains lexicographic.
+    ParentVisit,
+    /// Distance from the source; orders each whole layer by the caller's key.
+    Depth,
+}
+
+struct PriorityBfsSearch {
+    order: Vec<usize>,
+    parent: Vec<Option<usize>>,
+    discovered: usize,
+    edges_scanned: usize,
+    queue_peak: usize,
+}
+
+/// Shared frontier loop of `priority_bfs` and the lexicographic
+/// `shortest_path_unweighted_with_order`. Returns as soon as `stop_at` is
+/// discovered, since its parent is final at that point.
+fn priority_bfs_kernel<K: Ord>(
+    graph: &Graph,
+    source_idx: usize,
+    priority: impl Fn(&str) -> K,
+    rank_by: FrontierRank,
+    stop_at: Option<usize>,
+) -> PriorityBfsSearch {
+    let nodes = graph.nodes_ordered();
+    let mut search = PriorityBfsSearch {
+        order: Vec::new(),
+        parent: vec![None; nodes.len()],
+        discovered: 1,
+        edges_scanned: 0,
+        queue_peak: 1,
+    };
+    if stop_at == Some(source_idx) {
+        return search;
+    }
+
+    let mut visited = vec![false; nodes.len()];
+    visited[source_idx] = true;
+    let mut depth = vec![0_usize; nodes.len()];
+    let mut frontier = BinaryHeap::new();
+    frontier.push(std::cmp::Reverse((
+        0_usize,
+        priority(nodes[source_idx]),
+        nodes[source_idx],
+        source_idx,
+    )));
+
+    while let Some(std::cmp::Reverse((_, _, _, current))) = frontier.pop() {
+        let rank = search.order.len();
+        search.order.push(current);
+        let Some(neighbors) = graph.neighbors_indices(current) else {
+            continue;
+        };
+        for &neighbor in neighbors {
+            search.edges_scanned += 1;
+            if visited[neighbor] {
+                continue;
+            }
+            visited[neighbor] = true;
+            search.parent[neighbor] = Some(current);
+            depth[neighbor] = depth[current] + 1;
+            search.discovered += 1;
+            if stop_at == Some(neighbor) {
+                return search;
+            }
+            let frontier_rank = match rank_by {
+                FrontierRank::ParentVisit => rank,
+                FrontierRank::Depth => depth[neighbor],
+            };
+            frontier.push(std::cmp::Reverse((
+                frontier_rank,
+                priority(nodes[neighbor]),
+                nodes[neighbor],
+                neighbor,
+            )));
+            search.queue_peak = search.queue_peak.max(frontier.len());
+        }
+    }
+
+    search
+}
+
 /// BFS layers from multiple sources on an undirected graph.
 /// Layer 0 = all sources, Layer 1 = neighbors of any source not already visited, etc.
 /// Matches `networkx.bfs_layers` with an iterable of sources.
@@ -27216,6 +29274,117 @@ pub fn graph_symmetric_difference(g1: &Graph, g2: &Graph) -> Graph {
     result
 }
 
+/// Multiscale backbone of a weighted graph by disparity filtering (Serrano,
+/// Boguñá & Vespignani, PNAS 2009).
+///
+/// At a node of degree `k` and strength `s`, an incident edge of weight `w`
+/// has the p-value `(1 - w / s)^(k - 1)` under the null model that the node
+/// spreads its strength uniformly at random over its edges. An edge survives
+/// when it is significant (`p < alpha`) from either endpoint. The p-value is
+/// undefined at a degree-1 node, whose single edge is always kept; a node of
+/// zero strength reports 1.0. Weights follow Dijkstra's rules (missing,
+/// non-finite or negative counts as 1.0). Self-loops stay out of degree and
+/// strength, report a p-value of 1.0 and are never kept.
+///
+/// The backbone keeps every node, and every node and edge attribute, of
+/// `graph`; it only ever drops edges. `include_p_values` adds the per-edge
+/// p-values to the report in canonical edge order.
+#[must_use]
+pub fn disparity_backbone(
+    graph: &Graph,
+    weight_attr: &str,
+    alpha: f64,
+    include_p_values: bool,
+) -> DisparityBackboneResult {
+    let nodes = graph.nodes_ordered();
+    let n = nodes.len();
+    let edges = graph.edges_ordered();
+    let mut degree = vec![0usize; n];
+    let mut strength = vec![0.0_f64; n];
+    let mut endpoints = Vec::with_capacity(edges.len());
+    for edge in &edges {
+        let left = graph
+            .get_node_index(&edge.left)
+            .expect("edge endpoint must be a graph node");
+        let right = graph
+            .get_node_index(&edge.right)
+            .expect("edge endpoint must be a graph node");
+        let weight = edge_weight_or_default(graph, &edge.left, &edge.right, weight_attr);
+        if left != right {
+            for endpoint in [left, right] {
+                degree[endpoint] += 1;
+                strength[endpoint] += weight;
+            }
+        }
+        endpoints.push((left, right, weight));
+    }
+    let p_value = |node: usize, weight: f64| match degree[node] {
+        1 => None,
+        k if strength[node] > 0.0 => Some((1.0 - weight / strength[node]).powf((k - 1) as f64)),
+        _ => Some(1.0),
+    };
+
+    let mut backbone = Graph::with_runtime_policy(graph.runtime_policy().clone());
+    for &node in &nodes {
+        if let Some(attrs) = graph.node_attrs(node) {
+            backbone.add_node_with_attrs(node, attrs.clone());
+        } else {
+            backbone.add_node(node);
+        }
+    }
+    let mut kept_edges = 0usize;
+    let mut significance = Vec::new();
+    for (edge, (left, right, weight)) in edges.into_iter().zip(endpoints) {
+        let (left_p_value, right_p_value) = if left == right {
+            (Some(1.0), Some(1.0))
+        } else {
+            (p_value(left, weight), p_value(right, weight))
+        };
+        let kept = left != right
+            && [left_p_value, right_p_value]
+                .into_iter()
+                .any(|p| p.is_none_or(|p| p < alpha));
+        if include_p_values {
+            let mut entry = DisparityEdgeSignificance {
+                left: edge.left.clone(),
+                right: edge.right.clone(),
+                weight,
+                left_p_value,
+                right_p_value,
+                kept,
+            };
+            if entry.left > entry.right {
+                std::mem::swap(&mut entry.left, &mut entry.right);
+                std::mem::swap(&mut entry.left_p_value, &mut entry.right_p_value);
+            }
+            significance.push(entry);
+        }
+        if kept {
+            kept_edges += 1;
+            let _ = backbone.add_edge_with_attrs(edge.left, edge.right, edge.attrs);
+        }
+    }
+    significance.sort_by(|a, b| (&a.left, &a.right).cmp(&(&b.left, &b.right)));
+
+    let edge_count = graph.edge_count();
+    DisparityBackboneResult {
+        backbone,
+        report: DisparityBackboneReport {
+            alpha,
+            kept_edges,
+            removed_edges: edge_count - kept_edges,
+            edge_significance: include_p_values.then_some(significance),
+            witness: ComplexityWitness {
+                algorithm: "disparity_backbone".to_owned(),
+                complexity_claim: "O(|V| + |E| log |E|)".to_owned(),
+                nodes_touched: n,
+                edges_scanned: edge_count,
+                queue_peak: 0,
+            },
+        },
+    }
+}
+
 /// Return the degree histogram of a graph.
 ///
 /// Returns a list where the i-th entry is the number of nodes with degree i.
@@ -48565,6 +50734,162 @@ pub fn simrank_similarity_directed(
     result
 }
 
+// ---------------------------------------------------------------------------
+// Dense adjacency / incidence matrices
+// ---------------------------------------------------------------------------
+
+/// Node labels in `ordering`, refusing graphs above `max_nodes`.
+fn dense_matrix_labels(
+    graph: &Graph,
+    ordering: MatrixOrdering,
+    max_nodes: usize,
+) -> Result<Vec<&str>, MatrixError> {
+    let mut labels = graph.nodes_ordered();
+    if labels.len() > max_nodes {
+        return Err(MatrixError::TooManyNodes {
+            nodes: labels.len(),
+            max_nodes,
+        });
+    }
+    if ordering == MatrixOrdering::SortedName {
+        labels.sort_unstable();
+    }
+    Ok(labels)
+}
+
+/// Dense adjacency matrix in row-major order.
+///
+/// With `weight_attr: None` every edge is 1.0; otherwise its weight is parsed
+/// like `GraphView::edge_weight` (non-numeric or non-finite counts as 1.0). A
+/// self-loop puts its weight once on the diagonal, as
+/// `networkx.to_numpy_array` does. Graphs with more than `max_nodes` nodes are
+/// rejected, as the output holds `|V|^2` entries.
+pub fn adjacency_matrix(
+    graph: &Graph,
+    weight_attr: Option<&str>,
+    ordering: MatrixOrdering,
+    max_nodes: usize,
+) -> Result<AdjacencyMatrix, MatrixError> {
+    let labels = dense_matrix_labels(graph, ordering, max_nodes)?;
+    let n = labels.len();
+    let mut position = vec![0usize; n];
+    for (row, label) in labels.iter().enumerate() {
+        let idx = graph
+            .get_node_index(label)
+            .expect("label was taken from the graph");
+        position[idx] = row;
+    }
+
+    let mut data = vec![0.0_f64; n * n];
+    for (left, right) in graph.edges_ordered_indices() {
+        let weight = GraphView::edge_weight_by_indices(graph, left, right, weight_attr);
+        let (row, column) = (position[left], position[right]);
+        data[row * n + column] = weight;
+        data[column * n + row] = weight;
+    }
+
+    Ok(AdjacencyMatrix {
+        labels: labels.into_iter().map(str::to_owned).collect(),
+        data,
+    })
+}
+
+/// Dense node-by-edge incidence matrix in row-major order, with rows in graph
+/// node order and columns in canonical edge order.
+///
+/// Unoriented, both endpoints of an edge hold 1.0. Oriented, the canonical
+/// `left` endpoint holds -1.0 and `right` holds 1.0. Self-loop columns are all
+/// zero, as in `networkx.incidence_matrix`. Graphs with more than `max_nodes`
+/// nodes are rejected.
+pub fn incidence_matrix(
+    graph: &Graph,
+    oriented: bool,
+    max_nodes: usize,
+) -> Result<IncidenceMatrix, MatrixError> {
+    let labels = dense_matrix_labels(graph, MatrixOrdering::GraphOrder, max_nodes)?;
+    let mut edges = graph
+        .edges_ordered()
+        .into_iter()
+        .map(|edge| {
+            if edge.left <= edge.right {
+                (edge.left, edge.right)
+            } else {
+                (edge.right, edge.left)
+            }
+        })
+        .collect::<Vec<_>>();
+    edges.sort_unstable();
+
+    let columns = edges.len();
+    let mut data = vec![0.0_f64; labels.len() * columns];
+    for (column, (left, right)) in edges.iter().enumerate() {
+        if left == right {
+            continue;
+        }
+        let row_of = |node: &str| {
+            graph
+                .get_node_index(node)
+                .expect("edge endpoint must be a graph node")
+        };
+        data[row_of(left) * columns + column] = if oriented { -1.0 } else { 1.0 };
+        data[row_of(right) * columns + column] = 1.0;
+    }
+
+    Ok(IncidenceMatrix {
+        labels: labels.into_iter().map(str::to_owned).collect(),
+        edges,
+        data,
+    })
+}
+
+/// Builds an undirected graph from a square, symmetric, row-major matrix.
+///
+/// Nodes are added in `labels` order. Every entry above `threshold` on or
+/// above the diagonal becomes an edge carrying its value as `weight`, the
+/// diagonal giving self-loops; this inverts [`adjacency_matrix`] for any
+/// threshold below the exported weights.
+pub fn from_adjacency_matrix(
+    labels: &[&str],
+    data: &[f64],
+    threshold: f64,
+) -> Result<Graph, MatrixError> {
+    let n = data.len().isqrt();
+    if n * n != data.len() {
+        return Err(MatrixError::NotSquare { len: data.len() });
+    }
+    if labels.len() != n {
+        return Err(MatrixError::LabelCountMismatch {
+            labels: labels.len(),
+            dimension: n,
+        });
+    }
+
+    let mut graph = Graph::strict();
+    for label in labels {
+        if !graph.add_node(*label) {
+            return Err(MatrixError::DuplicateLabel((*label).to_owned()));
+        }
+    }
+    for row in 0..n {
+        for column in row..n {
+            let (value, mirror) = (data[row * n + column], data[column * n + row]);
+            // `0.0` mirrors `-0.0`; NaN mirrors NaN (and never exceeds the threshold).
+            if value != mirror && !(value.is_nan() && mirror.is_nan()) {
+                return Err(MatrixError::Asymmetric {
+                    row: labels[row].to_owned(),
+                    column: labels[column].to_owned(),
+                });
+            }
+            if value > threshold {
+                let mut attrs = AttrMap::new();
+                attrs.insert("weight".to_owned(), CgseValue::Float(value));
+                let _ = graph.add_edge_with_attrs(labels[row], labels[column], attrs);
+            }
+        }
+    }
+    Ok(graph)
+}
+
 // ---------------------------------------------------------------------------
 // Google PageRank matrix
 // ---------------------------------------------------------------------------
@@ -52805,14 +55130,32 @@ mod tests {
         CgseValue,
         ChordalGraphTreewidthError,
         ComplexityWitness,
+        DEFAULT_DENSE_MATRIX_MAX_NODES,
+        DegreeConstrainedObjective,
         FlowEdgeValue,
         FlowError,
+        FrontierOrder,
         GraphMLWriterConfig,
+        GraphSizeStats,
+        GraphletOrbitCountsResult,
+        GraphletOrbitError,
+        IncidenceMatrix,
         LinkPredictionEndpointPairs,
+        MAX_DECIMAL_WEIGHT_PLACES,
+        MAX_UNORDERED_WAYPOINTS,
+        MatrixError,
+        MatrixOrdering,
         MaximalIndependentSetError,
         ModularityError,
         SpannerError,
+        WaypointLeg,
+        WaypointRoutingError,
+        WeightPrecision,
+        WeightPrecisionError,
+        WeightPrecisionIssue,
+        WitnessDelta,
         adamic_adar_index,
+        adjacency_matrix,
         all_pairs_all_shortest_paths,
         all_pairs_bellman_ford_path,
         all_pairs_bellman_ford_path_length,
@@ -52838,6 +55181,7 @@ mod tests {
         antichains,
         arborescence_iterator,
         articulation_points,
+        assert_witness_delta_within,
         // A* and Yen's K-shortest
         astar_path,
         astar_path_length,
@@ -52911,6 +55255,7 @@ mod tests {
         dag_longest_path_length,
         dedensify,
         degree_centrality,
+        degree_constrained_subgraph,
         degree_histogram,
         degree_mixing_dict,
         degree_mixing_dict_directed,
@@ -52929,8 +55274,10 @@ mod tests {
         // Additional shortest path algorithms
         dijkstra_path_length,
         dijkstra_path_length_directed,
+        disparity_backbone,
         dodecahedral_graph,
         dominance_frontiers,
+        dominant_term_from_complexity_claim,
         dominating_set,
         edge_betweenness_centrality,
         // Traversal — additional
@@ -52954,6 +55301,7 @@ mod tests {
         eulerian_circuit,
         eulerian_path,
         eulerian_path_directed,
+        expected_delta_bounds,
         fast_could_be_isomorphic,
         faster_could_be_isomorphic,
         find_asteroidal_triple,
@@ -52965,6 +55313,7 @@ mod tests {
         flow_hierarchy_directed,
         floyd_warshall,
         floyd_warshall_predecessor_and_distance,
+        from_adjacency_matrix,
         frucht_graph,
         full_join,
         full_rary_tree,
@@ -52989,6 +55338,7 @@ mod tests {
         graph_intersection,
         graph_symmetric_difference,
         graph_union,
+        graphlet_orbit_counts,
         greedy_modularity_communities,
         grid_2d_graph,
         group_betweenness_centrality,
@@ -53012,6 +55362,7 @@ mod tests {
         immediate_dominators,
         // Additional centrality
         in_degree_centrality,
+        incidence_matrix,
         intersection_all,
         // DAG — additional
         is_aperiodic,
@@ -53072,6 +55423,7 @@ mod tests {
         krackhardt_kite_graph,
         label_propagation_communities,
         ladder_graph,
+        lexicographic_bfs_order,
         lexicographic_topological_sort,
         line_graph,
         line_graph_directed,
@@ -53090,6 +55442,7 @@ mod tests {
         max_flow_edmonds_karp_directed,
         max_weight_clique,
         max_weight_matching,
+        max_weight_matching_with_precision,
         maximal_independent_set,
         maximal_matching,
         maximum_branching,
@@ -53098,8 +55451,10 @@ mod tests {
         maximum_spanning_tree,
         min_edge_cover,
         min_weight_matching,
+        min_weight_matching_with_precision,
         // Approximation algorithms
         min_weighted_vertex_cover,
+        minimax_path,
         minimum_branching,
         minimum_cut_edmonds_karp,
         minimum_cut_edmonds_karp_directed,
@@ -53122,6 +55477,9 @@ mod tests {
         node_connected_component,
         node_disjoint_paths,
         node_expansion,
+        node_weight_or_default,
+        node_weighted_closeness_centrality,
+        node_weighted_degree_centrality,
         nodes_with_selfloops,
         non_edges,
         non_neighbors,
@@ -53148,6 +55506,7 @@ mod tests {
         power,
         predecessor,
         preferential_attachment,
+        priority_bfs,
         quotient_graph,
         ra_index_soundarajan_hopcroft,
         random_spanning_tree,
@@ -53169,7 +55528,10 @@ mod tests {
         sedgewick_maze_graph,
         selfloop_edges,
         shortest_path_unweighted,
+        shortest_path_unweighted_with_order,
+        shortest_path_via_waypoints,
         shortest_path_weighted,
+        shortest_path_weighted_with_precision,
         shortest_simple_paths,
         // Cycle detection
         simple_cycles,
@@ -53214,6 +55576,7 @@ mod tests {
         tree_broadcast_time,
         tree_data,
         triadic_census,
+        triangles,
         trivial_graph,
         truncated_cube_graph,
         truncated_tetrahedron_graph,
@@ -53225,6 +55588,7 @@ mod tests {
         voronoi_cells,
         weakly_connected_components,
         wheel_graph,
+        widest_path,
         wiener_index,
         windmill_graph,
         write_graphml_string,
@@ -53232,12 +55596,10 @@ mod tests {
         write_graphml_string_config_with_graph_attrs,
         write_graphml_string_directed,
     };
+    use crate::test_support::StructuredLogBuilder;
     use fnx_classes::Graph;
     use fnx_classes::digraph::DiGraph;
-    use fnx_runtime::{
-        CompatibilityMode, ForensicsBundleIndex, RuntimePolicy, StructuredTestLog, TestKind,
-        TestStatus, canonical_environment_fingerprint, structured_test_log_schema_version,
-    };
+    use fnx_runtime::{CompatibilityMode, RuntimePolicy, TestKind};
     use proptest::prelude::*;
     use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
 
@@ -63151,26 +65513,6 @@ mod tests {
         );
     }
 
-    fn packet_005_forensics_bundle(
-        run_id: &str,
-        test_id: &str,
-        replay_ref: &str,
-        bundle_id: &str,
-        artifact_refs: Vec<String>,
-    ) -> ForensicsBundleIndex {
-        ForensicsBundleIndex {
-            bundle_id: bundle_id.to_owned(),
-            run_id: run_id.to_owned(),
-            test_id: test_id.to_owned(),
-            bundle_hash_id: "bundle-hash-p2c005".to_owned(),
-            captured_unix_ms: 1,
-            replay_ref: replay_ref.to_owned(),
-            artifact_refs,
-            raptorq_sidecar_refs: Vec::new(),
-            decode_proof_refs: Vec::new(),
-        }
-    }
-
     fn attrs<const N: usize>(entries: [(&str, &str); N]) -> BTreeMap<String, CgseValue> {
         entries
             .into_iter()
@@ -63328,6 +65670,1174 @@ mod tests {
         assert_eq!(right.witness, right_replay.witness);
     }
 
+    fn diamond_insertion_variants() -> (Graph, Graph) {
+        let mut insertion_a = Graph::strict();
+        for (left, right) in [("a", "b"), ("a", "c"), ("b", "d"), ("c", "d")] {
+            insertion_a
+                .add_edge(left, right)
+                .expect("edge add should succeed");
+        }
+        let mut insertion_b = Graph::strict();
+        for (left, right) in [("c", "d"), ("a", "c"), ("b", "d"), ("a", "b")] {
+            insertion_b
+                .add_edge(left, right)
+                .expect("edge add should succeed");
+        }
+        (insertion_a, insertion_b)
+    }
+
+    #[test]
+    fn lexicographic_frontier_shortest_path_ignores_insertion_order() {
+        let (insertion_a, insertion_b) = diamond_insertion_variants();
+        let expected = Some(vec!["a", "b", "d"].into_iter().map(str::to_owned).collect());
+
+        for graph in [&insertion_a, &insertion_b] {
+            let result =
+                shortest_path_unweighted_with_order(graph, "a", "d", FrontierOrder::Lexicographic);
+            assert_eq!(result.path, expected);
+            assert_eq!(result.witness.algorithm, "lexicographic_bfs_shortest_path");
+            assert_eq!(
+                result,
+                shortest_path_unweighted_with_order(graph, "a", "d", FrontierOrder::Lexicographic)
+            );
+        }
+
+        // FIFO keeps the insertion-order-dependent answer pinned above.
+        assert_eq!(
+            shortest_path_unweighted_with_order(&insertion_b, "a", "d", FrontierOrder::Fifo).path,
+            Some(vec!["a", "c", "d"].into_iter().map(str::to_owned).collect())
+        );
+    }
+
+    #[test]
+    fn lexicographic_frontier_returns_smallest_of_all_shortest_paths() {
+        let grid = super::grid_2d_graph(4, 4);
+        let nodes = grid
+            .nodes_ordered()
+            .into_iter()
+            .map(str::to_owned)
+            .collect::<Vec<String>>();
+        for source in &nodes {
+            for target in &nodes {
+                let lexicographic = shortest_path_unweighted_with_order(
+                    &grid,
+                    source,
+                    target,
+                    FrontierOrder::Lexicographic,
+                );
+                let smallest = all_shortest_paths(&grid, source, target)
+                    .into_iter()
+                    .min()
+                    .expect("grid is connected");
+                assert_eq!(lexicographic.path, Some(smallest));
+            }
+        }
+    }
+
+    #[test]
+    fn fifo_frontier_order_reproduces_shortest_path_unweighted() {
+        assert_eq!(FrontierOrder::default(), FrontierOrder::Fifo);
+
+        let (insertion_a, insertion_b) = diamond_insertion_variants();
+        let mut disconnected = Graph::strict();
+        disconnected
+            .add_edge("a", "b")
+            .expect("edge add should succeed");
+        disconnected
+            .add_edge("c", "d")
+            .expect("edge add should succeed");
+        let grid = super::grid_2d_graph(3, 3);
+
+        for graph in [&insertion_a, &insertion_b, &disconnected, &grid] {
+            let mut endpoints = graph
+                .nodes_ordered()
+                .into_iter()
+                .map(str::to_owned)
+                .collect::<Vec<String>>();
+            endpoints.push("missing".to_owned());
+            for source in &endpoints {
+                for target in &endpoints {
+                    assert_eq!(
+                        shortest_path_unweighted_with_order(
+                            graph,
+                            source,
+                            target,
+                            FrontierOrder::default()
+                        ),
+                        shortest_path_unweighted(graph, source, target)
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn lexicographic_bfs_order_on_grid_is_pinned() {
+        let grid = super::grid_2d_graph(3, 3);
+        let result = lexicographic_bfs_order(&grid, "0,0");
+        assert_eq!(
+            result.order,
+            vec![
+                "0,0", "0,1", "1,0", "0,2", "1,1", "2,0", "1,2", "2,1", "2,2"
+            ]
+        );
+        let parent_of = |node: &str| {
+            result
+                .parents
+                .iter()
+                .find(|(candidate, _)| candidate == node)
+                .and_then(|(_, parent)| parent.clone())
+        };
+        assert_eq!(parent_of("0,0"), None);
+        assert_eq!(parent_of("1,1").as_deref(), Some("0,1"));
+        assert_eq!(parent_of("2,1").as_deref(), Some("1,1"));
+        assert_eq!(parent_of("2,2").as_deref(), Some("1,2"));
+        assert_eq!(result.witness.algorithm, "lexicographic_bfs");
+        assert_eq!(result.witness.nodes_touched, 9);
+        assert_eq!(result.witness.edges_scanned, 2 * grid.edge_count());
+
+        // Same grid with edges inserted in reverse yields the identical traversal.
+        let mut reversed = Graph::strict();
+        for node in grid.nodes_ordered().into_iter().rev() {
+            let _ = reversed.add_node(node);
+        }
+        for (left, right) in canonical_edge_pairs(&grid).into_iter().rev() {
+            reversed
+                .add_edge(&right, &left)
+                .expect("edge add should succeed");
+        }
+        let replay = lexicographic_bfs_order(&reversed, "0,0");
+        assert_eq!(replay.order, result.order);
+        assert_eq!(replay.parents, result.parents);
+    }
+
+    #[test]
+    fn priority_bfs_orders_siblings_by_caller_key() {
+        let (insertion_a, insertion_b) = diamond_insertion_variants();
+        for graph in [&insertion_a, &insertion_b] {
+            let result = priority_bfs(graph, "a", |node| std::cmp::Reverse(node.to_owned()));
+            assert_eq!(result.order, vec!["a", "c", "b", "d"]);
+            assert_eq!(
+                result.parents,
+                vec![
+                    ("a".to_owned(), None),
+                    ("c".to_owned(), Some("a".to_owned())),
+                    ("b".to_owned(), Some("a".to_owned())),
+                    ("d".to_owned(), Some("c".to_owned())),
+                ]
+            );
+            assert_eq!(result.witness.algorithm, "priority_bfs");
+        }
+
+        // Equal keys fall back to node name.
+        let constant = priority_bfs(&insertion_b, "a", |_| 0_u8);
+        assert_eq!(constant.order, vec!["a", "b", "c", "d"]);
+
+        let missing = priority_bfs(&insertion_a, "zz", |_| 0_u8);
+        assert!(missing.order.is_empty());
+        assert!(missing.parents.is_empty());
+        assert_eq!(missing.witness.nodes_touched, 0);
+    }
+
+    #[test]
+    fn priority_bfs_orders_whole_layers_by_caller_key() {
+        let mut graph = Graph::strict();
+        for (left, right) in [("a", "b"), ("a", "c"), ("b", "y"), ("c", "x")] {
+            graph
+                .add_edge(left, right)
+                .expect("edge add should succeed");
+        }
+        // The key ranks `x` ahead of `y` even though `y`'s parent `b` is visited first.
+        let key = |node: &str| match node {
+            "c" | "y" => 1_u8,
+            _ => 0,
+        };
+        let result = priority_bfs(&graph, "a", key);
+        assert_eq!(result.order, vec!["a", "b", "c", "x", "y"]);
+        assert_eq!(
+            result.parents,
+            vec![
+                ("a".to_owned(), None),
+                ("b".to_owned(), Some("a".to_owned())),
+                ("c".to_owned(), Some("a".to_owned())),
+                ("x".to_owned(), Some("c".to_owned())),
+                ("y".to_owned(), Some("b".to_owned())),
+            ]
+        );
+
+        // Parent order still governs the lexicographic traversal.
+        let lexicographic = lexicographic_bfs_order(&graph, "a");
+        assert_eq!(lexicographic.order, vec!["a", "b", "c", "y", "x"]);
+    }
+
+    #[test]
+    fn waypoint_route_on_grid_pins_ordered_and_unordered_modes() {
+        let grid = super::grid_2d_graph(3, 3);
+        let waypoints = ["2,2", "1,0"];
+
+        let chained = shortest_path_via_waypoints(&grid, "0,0", "0,2", &waypoints, None, true)
+            .expect("all nodes exist");
+        assert_eq!(chained.waypoint_order, vec!["2,2", "1,0"]);
+        assert_eq!(
+            chained.path.expect("grid is connected"),
+            vec![
+                "0,0", "0,1", "0,2", "1,2", "2,2", "1,2", "1,1", "1,0", "0,0", "0,1", "0,2"
+            ]
+        );
+        assert_eq!(chained.total_weight, Some(10.0));
+        assert_eq!(chained.unreachable_leg, None);
+
+        let solved = shortest_path_via_waypoints(&grid, "0,0", "0,2", &waypoints, None, false)
+            .expect("all nodes exist");
+        assert_eq!(solved.waypoint_order, vec!["1,0", "2,2"]);
+        assert_eq!(
+            solved.path.expect("grid is connected"),
+            vec!["0,0", "1,0", "1,1", "1,2", "2,2", "1,2", "0,2"]
+        );
+        assert_eq!(solved.total_weight, Some(6.0));
+        assert_eq!(solved.witness.algorithm, "shortest_path_via_waypoints");
+
+        // Edge weights steer the unordered solver as well as each leg.
+        let mut weighted = grid.clone();
+        let mut attrs = AttrMap::new();
+        attrs.insert("weight".to_owned(), CgseValue::Float(10.0));
+        weighted
+            .add_edge_with_attrs("0,0", "1,0", attrs)
+            .expect("edge update should succeed");
+        let detour =
+            shortest_path_via_waypoints(&weighted, "0,0", "0,2", &waypoints, Some("weight"), false)
+                .expect("all nodes exist");
+        assert_eq!(detour.waypoint_order, vec!["1,0", "2,2"]);
+        assert_eq!(detour.total_weight, Some(8.0));
+        let detour_chained =
+            shortest_path_via_waypoints(&weighted, "0,0", "0,2", &waypoints, Some("weight"), true)
+                .expect("all nodes exist");
+        assert_eq!(detour_chained.total_weight, Some(10.0));
+        // The solver searched every terminal pair; chaining only the three legs.
+        assert!(detour.witness.edges_scanned > detour_chained.witness.edges_scanned);
+    }
+
+    #[test]
+    fn waypoint_route_collapses_waypoint_equal_to_source() {
+        let grid = super::grid_2d_graph(2, 3);
+        for ordered in [true, false] {
+            let result =
+                shortest_path_via_waypoints(&grid, "0,0", "1,2", &["0,0", "1,1"], None, ordered)
+                    .expect("all nodes exist");
+            assert_eq!(result.waypoint_order, vec!["0,0", "1,1"]);
+            assert_eq!(
+                result.path.expect("grid is connected"),
+                vec!["0,0", "0,1", "1,1", "1,2"]
+            );
+            assert_eq!(result.total_weight, Some(3.0));
+        }
+
+        let trivial = shortest_path_via_waypoints(&grid, "0,0", "0,0", &[], None, false)
+            .expect("all nodes exist");
+        assert_eq!(trivial.path, Some(vec!["0,0".to_owned()]));
+        assert_eq!(trivial.total_weight, Some(0.0));
+        assert!(trivial.waypoint_order.is_empty());
+    }
+
+    #[test]
+    fn waypoint_route_reports_unreachable_leg() {
+        let mut graph = Graph::strict();
+        for (left, right) in [("a", "b"), ("b", "c"), ("x", "y")] {
+            graph
+                .add_edge(left, right)
+                .expect("edge add should succeed");
+        }
+        let _ = graph.add_node("island");
+        for ordered in [true, false] {
+            let result = shortest_path_via_waypoints(
+                &graph,
+                "a",
+                "c",
+                &["b", "island", "c"],
+                Some("weight"),
+                ordered,
+            )
+            .expect("all nodes exist");
+            assert_eq!(result.path, None);
+            assert_eq!(result.total_weight, None);
+            assert_eq!(result.waypoint_order, vec!["b", "island", "c"]);
+            assert_eq!(
+                result.unreachable_leg,
+                Some(WaypointLeg {
+                    source: "b".to_owned(),
+                    target: "island".to_owned(),
+                })
+            );
+        }
+
+        let missing = shortest_path_via_waypoints(&graph, "a", "c", &["zz"], None, true);
+        assert_eq!(
+            missing,
+            Err(WaypointRoutingError::NodeNotFound("zz".to_owned()))
+        );
+    }
+
+    #[test]
+    fn waypoint_route_rejects_oversized_unordered_sets() {
+        let grid = super::grid_2d_graph(4, 4);
+        let nodes = grid.nodes_ordered();
+        let waypoints = &nodes[1..=MAX_UNORDERED_WAYPOINTS + 1];
+        assert_eq!(waypoints.len(), 11);
+
+        let err = shortest_path_via_waypoints(&grid, "0,0", "3,3", waypoints, None, false)
+            .expect_err("k = 11 exceeds the exact solver");
+        assert_eq!(
+            err,
+            WaypointRoutingError::TooManyWaypoints {
+                count: 11,
+                max: MAX_UNORDERED_WAYPOINTS,
+            }
+        );
+        assert!(err.to_string().contains("at most 10 waypoints, got 11"));
+
+        let chained = shortest_path_via_waypoints(&grid, "0,0", "3,3", waypoints, None, true)
+            .expect("ordered routing has no size cap");
+        assert!(chained.path.is_some());
+
+        let solved =
+            shortest_path_via_waypoints(&grid, "0,0", "3,3", &waypoints[..10], None, false)
+                .expect("k = 10 is within the exact solver");
+        assert_eq!(solved.waypoint_order.len(), 10);
+    }
+
+    /// Orbit counts by classifying every induced 3- and 4-node subset.
+    fn graphlet_orbit_counts_by_enumeration(graph: &Graph) -> Vec<Vec<u64>> {
+        let n = graph.node_count();
+        let adjacent = |u: usize, v: usize| {
+            u != v
+                && graph
+                    .neighbors_indices(u)
+                    .is_some_and(|row| row.contains(&v))
+        };
+        let mut counts = vec![vec![0u64; 15]; n];
+        for (x, row) in counts.iter_mut().enumerate() {
+            row[0] = (0..n).filter(|&y| adjacent(x, y)).count() as u64;
+        }
+        let mut subset = Vec::new();
+        for a in 0..n {
+            for b in a + 1..n {
+                for c in b + 1..n {
+                    subset.clear();
+                    subset.extend([a, b, c]);
+                    let degrees = subset
+                        .iter()
+                        .map(|&u| subset.iter().filter(|&&v| adjacent(u, v)).count())
+                        .collect::<Vec<_>>();
+                    for (&u, &deg) in subset.iter().zip(&degrees) {
+                        match degrees.iter().sum::<usize>() / 2 {
+                            2 => counts[u][deg] += 1,
+                            3 => counts[u][3] += 1,
+                            _ => {}
+                        }
+                    }
+                    for d in c + 1..n {
+                        subset.push(d);
+                        let degrees = subset
+                            .iter()
+                            .map(|&u| subset.iter().filter(|&&v| adjacent(u, v)).count())
+                            .collect::<Vec<_>>();
+                        let edges = degrees.iter().sum::<usize>() / 2;
+                        let is_star = degrees.contains(&3);
+                        for (&u, &deg) in subset.iter().zip(&degrees) {
+                            let orbit = match (edges, is_star, deg) {
+                                (3, false, 1) if !degrees.contains(&0) => Some(4),
+                                (3, false, 2) if !degrees.contains(&0) => Some(5),
+                                (3, true, 1) => Some(6),
+                                (3, true, 3) => Some(7),
+                                (4, false, _) => Some(8),
+                                (4, true, 1) => Some(9),
+                                (4, true, 2) => Some(10),
+                                (4, true, 3) => Some(11),
+                                (5, _, 2) => Some(12),
+                                (5, _, 3) => Some(13),
+                                (6, _, _) => Some(14),
+                                _ => None,
+                            };
+                            if let Some(orbit) = orbit {
+                                counts[u][orbit] += 1;
+                            }
+                        }
+                        subset.pop();
+                    }
+                }
+            }
+        }
+        counts
+    }
+
+    #[test]
+    fn graphlet_orbits_on_clique_and_path_are_pinned() {
+        let mut k4 = Graph::strict();
+        for (left, right) in [
+            ("a", "b"),
+            ("a", "c"),
+            ("a", "d"),
+            ("b", "c"),
+            ("b", "d"),
+            ("c", "d"),
+        ] {
+            k4.add_edge(left, right).expect("edge add should succeed");
+        }
+        let result = graphlet_orbit_counts(&k4, 4).expect("size 4 is supported");
+        assert_eq!(result.orbit_labels.len(), 15);
+        assert_eq!(result.orbit_labels[14], "clique4");
+        for row in &result.counts {
+            assert_eq!(row, &vec![3, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1]);
+        }
+        assert_eq!(total_of(&result, "clique4"), 1);
+        assert_eq!(total_of(&result, "triangle"), 4);
+        assert_eq!(total_of(&result, "path4"), 0);
+        assert!(result.neighbor_triples_enumerated > 0);
+        assert_eq!(result.witness.algorithm, "graphlet_orbit_counts");
+
+        let mut p4 = Graph::strict();
+        for (left, right) in [("a", "b"), ("b", "c"), ("c", "d")] {
+            p4.add_edge(left, right).expect("edge add should succeed");
+        }
+        let result = graphlet_orbit_counts(&p4, 4).expect("size 4 is supported");
+        assert_eq!(result.nodes, vec!["a", "b", "c", "d"]);
+        let end = vec![1, 1, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0];
+        let inner = vec![2, 1, 1, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0];
+        assert_eq!(result.counts, vec![end.clone(), inner.clone(), inner, end]);
+        assert_eq!(total_of(&result, "path4"), 1);
+        assert_eq!(total_of(&result, "path3"), 2);
+        assert_eq!(result.neighbor_triples_enumerated, 0);
+
+        let small = graphlet_orbit_counts(&p4, 3).expect("size 3 is supported");
+        assert_eq!(
+            small.orbit_labels,
+            vec!["edge", "path3_end", "path3_center", "triangle"]
+        );
+        assert_eq!(small.counts[0], vec![1, 1, 0, 0]);
+        assert_eq!(small.graphlet_totals.len(), 3);
+
+        assert_eq!(
+            graphlet_orbit_counts(&p4, 5),
+            Err(GraphletOrbitError::UnsupportedSize(5))
+        );
+    }
+
+    fn total_of(result: &GraphletOrbitCountsResult, label: &str) -> u64 {
+        result
+            .graphlet_totals
+            .iter()
+            .find(|(name, _)| name == label)
+            .map_or(0, |(_, count)| *count)
+    }
+
+    #[test]
+    fn graphlet_orbits_are_independent_of_insertion_order() {
+        let edges = [
+            ("a", "b"),
+            ("b", "c"),
+            ("c", "a"),
+            ("c", "d"),
+            ("d", "e"),
+            ("e", "b"),
+            ("e", "f"),
+            ("f", "g"),
+            ("g", "d"),
+        ];
+        let mut forward = Graph::strict();
+        for (left, right) in edges {
+            forward
+                .add_edge(left, right)
+                .expect("edge add should succeed");
+        }
+        let mut backward = Graph::strict();
+        for (left, right) in edges.iter().rev() {
+            backward
+                .add_edge(*right, *left)
+                .expect("edge add should succeed");
+        }
+        let by_node = |graph: &Graph| {
+            let result = graphlet_orbit_counts(graph, 4).expect("size 4 is supported");
+            result
+                .nodes
+                .into_iter()
+                .zip(result.counts)
+                .collect::<BTreeMap<_, _>>()
+        };
+        let forward_counts = by_node(&forward);
+        assert_eq!(forward_counts, by_node(&backward));
+        assert_eq!(
+            forward_counts.values().cloned().collect::<Vec<_>>(),
+            graphlet_orbit_counts_by_enumeration(&forward)
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn property_graphlet_orbits_match_enumeration(edges in prop::collection::vec((0_u8..9, 0_u8..9), 0..30)) {
+            let mut graph = Graph::strict();
+            for (left, right) in &edges {
+                graph
+                    .add_edge(format!("n{left}"), format!("n{right}"))
+                    .expect("generated edge insertion should succeed");
+            }
+
+            let result = graphlet_orbit_counts(&graph, 4).expect("size 4 is supported");
+            prop_assert_eq!(&result.counts, &graphlet_orbit_counts_by_enumeration(&graph));
+
+            let triangle_orbits = result.counts.iter().map(|row| row[3]).collect::<Vec<_>>();
+            prop_assert_eq!(
+                triangle_orbits.iter().sum::<u64>(),
+                3 * total_of(&result, "triangle")
+            );
+            let reference = triangles(&graph)
+                .triangles
+                .iter()
+                .map(|entry| entry.count as u64)
+                .collect::<Vec<_>>();
+            prop_assert_eq!(triangle_orbits, reference);
+
+            let small = graphlet_orbit_counts(&graph, 3).expect("size 3 is supported");
+            for (short, full) in small.counts.iter().zip(&result.counts) {
+                prop_assert_eq!(&short[..], &full[..4]);
+            }
+        }
+    }
+
+    fn with_node_weight(graph: &mut Graph, node: &str, attr: &str, value: CgseValue) {
+        let mut attrs = AttrMap::new();
+        attrs.insert(attr.to_owned(), value);
+        graph.add_node_with_attrs(node, attrs);
+    }
+
+    fn score_of(scores: &[CentralityScore], node: &str) -> f64 {
+        scores
+            .iter()
+            .find(|entry| entry.node == node)
+            .map(|entry| entry.score)
+            .expect("node should be scored")
+    }
+
+    #[test]
+    fn node_weighted_degree_rewards_heavy_leaf_neighbors() {
+        let mut star = Graph::strict();
+        for leaf in ["a", "b", "c"] {
+            star.add_edge("hub", leaf).expect("edge add should succeed");
+        }
+        let _ = star.add_node("z");
+
+        let uniform = node_weighted_degree_centrality(&star, "pop");
+        assert_eq!(
+            uniform.defaulted_weight_nodes,
+            vec!["hub", "a", "b", "c", "z"]
+        );
+        assert!((score_of(&uniform.scores, "hub") - 0.75).abs() < TEST_TOLERANCE);
+
+        with_node_weight(&mut star, "a", "pop", CgseValue::Float(5.0));
+        let heavy = node_weighted_degree_centrality(&star, "pop");
+        assert_eq!(heavy.defaulted_weight_nodes, vec!["hub", "b", "c", "z"]);
+        assert!((score_of(&heavy.scores, "hub") - 7.0 / 8.0).abs() < TEST_TOLERANCE);
+        assert!(score_of(&heavy.scores, "hub") > score_of(&uniform.scores, "hub"));
+        assert!((score_of(&heavy.scores, "b") - 1.0 / 8.0).abs() < TEST_TOLERANCE);
+        assert_eq!(score_of(&heavy.scores, "z"), 0.0);
+
+        // Unusable values are defaulted and reported like missing ones.
+        with_node_weight(&mut star, "b", "pop", CgseValue::Float(f64::NAN));
+        with_node_weight(&mut star, "c", "pop", CgseValue::String("-2".to_owned()));
+        let defaulted = node_weighted_degree_centrality(&star, "pop");
+        assert_eq!(defaulted.scores, heavy.scores);
+        assert_eq!(defaulted.defaulted_weight_nodes, vec!["hub", "b", "c", "z"]);
+        assert_eq!(node_weight_or_default(&star, "a", "pop"), 5.0);
+        assert_eq!(node_weight_or_default(&star, "c", "pop"), 1.0);
+        assert_eq!(node_weight_or_default(&star, "missing", "pop"), 1.0);
+    }
+
+    #[test]
+    fn node_weighted_closeness_on_population_path_is_hand_computed() {
+        let mut path = Graph::strict();
+        for (left, right) in [("a", "b"), ("b", "c"), ("c", "d")] {
+            path.add_edge(left, right).expect("edge add should succeed");
+        }
+        for (node, population) in [("a", 1), ("b", 2), ("c", 3), ("d", 4)] {
+            with_node_weight(&mut path, node, "pop", CgseValue::Int(population));
+        }
+
+        // W = 10; a: R = 9, D = 2*1 + 3*2 + 4*3 = 20 -> (9/20) * (9/9).
+        // b: R = 8, D = 1 + 3 + 4*2 = 12; c: R = 7, D = 2 + 4 + 1*2 = 8;
+        // d: R = 6, D = 3 + 2*2 + 1*3 = 10.
+        let result = node_weighted_closeness_centrality(&path, "pop");
+        let expected = [("a", 0.45), ("b", 8.0 / 12.0), ("c", 0.875), ("d", 0.6)];
+        assert_eq!(
+            result
+                .scores
+                .iter()
+                .map(|entry| entry.node.as_str())
+                .collect::<Vec<_>>(),
+            vec!["a", "b", "c", "d"]
+        );
+        for (node, score) in expected {
+            assert!(
+                (score_of(&result.scores, node) - score).abs() < TEST_TOLERANCE,
+                "{node} closeness drifted"
+            );
+        }
+        assert!(result.defaulted_weight_nodes.is_empty());
+        assert_eq!(
+            result.witness.algorithm,
+            "node_weighted_closeness_centrality"
+        );
+        assert_eq!(result.witness.nodes_touched, 4);
+        assert_eq!(result.witness.edges_scanned, 4 * 2 * path.edge_count());
+        assert_eq!(node_weighted_closeness_centrality(&path, "pop"), result);
+
+        // An unreachable heavy node only lowers scores through the W - w(u) term.
+        with_node_weight(&mut path, "far", "pop", CgseValue::Float(10.0));
+        let split = node_weighted_closeness_centrality(&path, "pop");
+        assert!((score_of(&split.scores, "a") - 0.45 * 9.0 / 19.0).abs() < TEST_TOLERANCE);
+        assert_eq!(score_of(&split.scores, "far"), 0.0);
+    }
+
+    proptest! {
+        #[test]
+        fn property_unit_node_weights_reproduce_unweighted_centrality(
+            edges in prop::collection::vec((0_u8..10, 0_u8..10), 0..30),
+            unit_nodes in prop::collection::vec(0_u8..10, 0..10),
+        ) {
+            let mut graph = Graph::strict();
+            for (left, right) in &edges {
+                graph
+                    .add_edge(format!("n{left}"), format!("n{right}"))
+                    .expect("generated edge insertion should succeed");
+            }
+            for node in &unit_nodes {
+                with_node_weight(&mut graph, &format!("n{node}"), "pop", CgseValue::Int(1));
+            }
+
+            let degree = node_weighted_degree_centrality(&graph, "pop");
+            prop_assert_eq!(&degree.scores, &degree_centrality(&graph).scores);
+            let closeness = node_weighted_closeness_centrality(&graph, "pop");
+            prop_assert_eq!(&closeness.scores, &closeness_centrality(&graph).scores);
+
+            let explicit = graph
+                .nodes_ordered()
+                .into_iter()
+                .filter(|node| {
+                    graph
+                        .node_attrs(node)
+                        .is_some_and(|attrs| attrs.contains_key("pop"))
+                })
+                .count();
+            prop_assert_eq!(
+                closeness.defaulted_weight_nodes.len(),
+                graph.node_count() - explicit
+            );
+            prop_assert_eq!(node_weighted_closeness_centrality(&graph, "pop"), closeness);
+        }
+    }
+
+    fn weighted_graph(edges: &[(&str, &str, f64)]) -> Graph {
+        let mut graph = Graph::strict();
+        for &(left, right, weight) in edges {
+            let mut attrs = AttrMap::new();
+            attrs.insert("weight".to_owned(), CgseValue::Float(weight));
+            graph
+                .add_edge_with_attrs(left, right, attrs)
+                .expect("edge add should succeed");
+        }
+        graph
+    }
+
+    fn assert_backbone_is_subgraph(graph: &Graph, backbone: &Graph) {
+        assert_eq!(backbone.nodes_ordered(), graph.nodes_ordered());
+        for edge in backbone.edges_ordered() {
+            assert!(graph.has_edge(&edge.left, &edge.right));
+        }
+        assert!(backbone.edge_count() <= graph.edge_count());
+    }
+
+    #[test]
+    fn disparity_backbone_keeps_dominant_star_edge() {
+        // Each light leaf also holds a heavy private edge, so it is not degree 1
+        // and its hub edge is judged on the disparity alone.
+        let mut edges = vec![("hub", "big", 100.0)];
+        let leaves = (0..9).map(|i| format!("leaf{i}")).collect::<Vec<_>>();
+        let pendants = (0..9).map(|i| format!("pendant{i}")).collect::<Vec<_>>();
+        for (leaf, pendant) in leaves.iter().zip(&pendants) {
+            edges.push(("hub", leaf, 1.0));
+            edges.push((leaf, pendant, 50.0));
+        }
+        let graph = weighted_graph(&edges);
+
+        let result = disparity_backbone(&graph, "weight", 0.05, false);
+        assert_backbone_is_subgraph(&graph, &result.backbone);
+        assert!(result.backbone.has_edge("hub", "big"));
+        for (leaf, pendant) in leaves.iter().zip(&pendants) {
+            assert!(!result.backbone.has_edge("hub", leaf));
+            assert!(result.backbone.has_edge(leaf, pendant));
+        }
+        assert_eq!(result.report.kept_edges, 10);
+        assert_eq!(result.report.removed_edges, 9);
+        assert_eq!(result.report.edge_significance, None);
+        assert_eq!(result.report.witness.algorithm, "disparity_backbone");
+    }
+
+    #[test]
+    fn disparity_backbone_on_uniform_clique_follows_alpha() {
+        let mut edges = Vec::new();
+        let names = ["a", "b", "c", "d", "e"];
+        for (i, left) in names.iter().enumerate() {
+            for right in &names[i + 1..] {
+                edges.push((*left, *right, 2.0));
+            }
+        }
+        let clique = weighted_graph(&edges);
+
+        // Every edge has p = (1 - 1/4)^3 = 0.421875 from both endpoints.
+        let loose = disparity_backbone(&clique, "weight", 0.99, true);
+        assert_eq!(loose.report.kept_edges, 10);
+        assert_eq!(loose.backbone.edge_count(), 10);
+        for entry in loose.report.edge_significance.expect("p-values requested") {
+            assert_eq!(entry.left_p_value, Some(0.421875));
+            assert_eq!(entry.right_p_value, Some(0.421875));
+        }
+
+        let strict = disparity_backbone(&clique, "weight", 1e-6, false);
+        assert_eq!(strict.report.kept_edges, 0);
+        assert_eq!(strict.report.removed_edges, 10);
+        assert_backbone_is_subgraph(&clique, &strict.backbone);
+        assert_eq!(strict.backbone.node_count(), 5);
+    }
+
+    #[test]
+    fn disparity_backbone_p_values_match_hand_computation() {
+        let graph = weighted_graph(&[("x", "c", 3.0), ("x", "a", 1.0), ("x", "b", 2.0)]);
+        let result = disparity_backbone(&graph, "weight", 0.3, true);
+        let significance = result.report.edge_significance.expect("p-values requested");
+
+        // x: k = 3, s = 6, so p = (1 - w/6)^2; the leaves have degree 1.
+        let expected = [
+            ("a", 1.0, 25.0 / 36.0),
+            ("b", 2.0, 4.0 / 9.0),
+            ("c", 3.0, 0.25),
+        ];
+        assert_eq!(significance.len(), 3);
+        for (entry, (leaf, weight, p_value)) in significance.iter().zip(expected) {
+            assert_eq!((entry.left.as_str(), entry.right.as_str()), (leaf, "x"));
+            assert_eq!(entry.weight, weight);
+            assert_eq!(entry.left_p_value, None);
+            let seen = entry.right_p_value.expect("x has degree 3");
+            assert!((seen - p_value).abs() < TEST_TOLERANCE);
+            // Degree-1 leaves keep their only edge whatever the p-value.
+            assert!(entry.kept);
+        }
+    }
+
+    #[test]
+    fn disparity_backbone_is_deterministic_and_preserves_attributes() {
+        let edges = [
+            ("a", "b", 9.0),
+            ("b", "c", 1.0),
+            ("c", "a", 1.0),
+            ("c", "d", 6.0),
+            ("d", "a", 1.0),
+            ("d", "e", 1.0),
+            ("e", "b", 1.0),
+        ];
+        let mut graph = weighted_graph(&edges);
+        with_node_weight(
+            &mut graph,
+            "a",
+            "label",
+            CgseValue::String("hub".to_owned()),
+        );
+        let _ = graph.add_node("isolated");
+        let mut extra = AttrMap::new();
+        extra.insert("kind".to_owned(), CgseValue::String("strong".to_owned()));
+        graph
+            .add_edge_with_attrs("a", "b", extra)
+            .expect("edge update should succeed");
+
+        let result = disparity_backbone(&graph, "weight", 0.2, true);
+        assert_backbone_is_subgraph(&graph, &result.backbone);
+        assert!(result.backbone.has_node("isolated"));
+        assert_eq!(
+            result
+                .backbone
+                .node_attrs("a")
+                .and_then(|attrs| attrs.get("label")),
+            Some(&CgseValue::String("hub".to_owned()))
+        );
+        assert!(result.backbone.has_edge("a", "b"));
+        assert_eq!(
+            result.backbone.edge_attrs("a", "b"),
+            graph.edge_attrs("a", "b")
+        );
+        assert_eq!(
+            result.report.kept_edges + result.report.removed_edges,
+            graph.edge_count()
+        );
+
+        let replay = disparity_backbone(&graph, "weight", 0.2, true);
+        assert_eq!(replay.report, result.report);
+        assert_eq!(
+            canonical_edge_pairs(&replay.backbone),
+            canonical_edge_pairs(&result.backbone)
+        );
+
+        let reversed = weighted_graph(
+            &edges
+                .iter()
+                .rev()
+                .map(|&(left, right, weight)| (right, left, weight))
+                .collect::<Vec<_>>(),
+        );
+        let mirrored = disparity_backbone(&reversed, "weight", 0.2, true);
+        assert_eq!(
+            mirrored.report.edge_significance,
+            result.report.edge_significance
+        );
+        assert_eq!(
+            canonical_edge_pairs(&mirrored.backbone),
+            canonical_edge_pairs(&result.backbone)
+        );
+    }
+
+    #[test]
+    fn widest_path_trades_hops_for_capacity() {
+        let graph = weighted_graph(&[
+            ("s", "t", 1.0),
+            ("s", "a", 5.0),
+            ("a", "b", 7.0),
+            ("b", "t", 5.0),
+            ("s", "c", 9.0),
+            ("c", "t", 2.0),
+        ]);
+
+        let widest = widest_path(&graph, "s", "t", "weight");
+        assert_eq!(
+            widest.path.as_deref(),
+            Some(&["s", "a", "b", "t"].map(str::to_owned)[..])
+        );
+        assert_eq!(widest.bottleneck, Some(5.0));
+        assert_eq!(widest.witness.algorithm, "widest_path");
+        let shortest = shortest_path_unweighted(&graph, "s", "t")
+            .path
+            .expect("s and t are adjacent");
+        assert!(widest.path.expect("t is reachable").len() > shortest.len());
+
+        // Read as costs, the direct edge already has the smallest maximum.
+        let minimax = minimax_path(&graph, "s", "t", "weight");
+        assert_eq!(minimax.path, Some(vec!["s".to_owned(), "t".to_owned()]));
+        assert_eq!(minimax.bottleneck, Some(1.0));
+        assert_eq!(minimax.witness.algorithm, "minimax_path");
+    }
+
+    #[test]
+    fn bottleneck_paths_handle_missing_and_disconnected_endpoints() {
+        let mut graph = weighted_graph(&[("a", "b", 3.0), ("c", "d", 4.0)]);
+        let _ = graph.add_node("lonely");
+        for search in [widest_path, minimax_path] {
+            let missing = search(&graph, "a", "zz", "weight");
+            assert_eq!((missing.path, missing.bottleneck), (None, None));
+            let split = search(&graph, "a", "d", "weight");
+            assert_eq!((split.path, split.bottleneck), (None, None));
+            let trivial = search(&graph, "lonely", "lonely", "weight");
+            assert_eq!(trivial.path, Some(vec!["lonely".to_owned()]));
+            assert_eq!(trivial.bottleneck, None);
+            let direct = search(&graph, "b", "a", "weight");
+            assert_eq!(direct.path, Some(vec!["b".to_owned(), "a".to_owned()]));
+            assert_eq!(direct.bottleneck, Some(3.0));
+        }
+
+        // Equal bottlenecks resolve by node name, whatever the insertion order.
+        let forward = weighted_graph(&[
+            ("s", "y", 2.0),
+            ("s", "x", 2.0),
+            ("x", "t", 2.0),
+            ("y", "t", 2.0),
+        ]);
+        let backward = weighted_graph(&[
+            ("y", "t", 2.0),
+            ("x", "t", 2.0),
+            ("s", "x", 2.0),
+            ("s", "y", 2.0),
+        ]);
+        for graph in [&forward, &backward] {
+            let widest = widest_path(graph, "s", "t", "weight");
+            assert_eq!(
+                widest.path,
+                Some(vec!["s", "x", "t"].into_iter().map(str::to_owned).collect())
+            );
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn property_bottleneck_paths_match_enumeration(
+            edges in prop::collection::vec((0_u8..8, 0_u8..8, 1_u8..7), 0..20),
+        ) {
+            let mut graph = Graph::strict();
+            for node in 0..8 {
+                let _ = graph.add_node(format!("n{node}"));
+            }
+            for (left, right, capacity) in &edges {
+                let mut attrs = AttrMap::new();
+                attrs.insert("cap".to_owned(), CgseValue::Int(i64::from(*capacity)));
+                graph
+                    .add_edge_with_attrs(format!("n{left}"), format!("n{right}"), attrs)
+                    .expect("generated edge insertion should succeed");
+            }
+            let capacity = |left: &str, right: &str| {
+                graph
+                    .edge_attrs(left, right)
+                    .and_then(|attrs| attrs.get("cap"))
+                    .and_then(CgseValue::as_f64)
+                    .expect("generated edges carry a capacity")
+            };
+            let path_extreme = |path: &[String], widest: bool| {
+                path.windows(2)
+                    .map(|pair| capacity(&pair[0], &pair[1]))
+                    .reduce(|a, b| if widest { a.min(b) } else { a.max(b) })
+            };
+
+            let (source, target) = ("n0", "n7");
+            let paths = all_simple_paths(&graph, source, target, None).paths;
+            for widest in [true, false] {
+                let result = if widest {
+                    widest_path(&graph, source, target, "cap")
+                } else {
+                    minimax_path(&graph, source, target, "cap")
+                };
+                let expected = paths
+                    .iter()
+                    .filter_map(|path| path_extreme(path, widest))
+                    .reduce(|a, b| if widest { a.max(b) } else { a.min(b) });
+                prop_assert_eq!(result.bottleneck, expected);
+                if let Some(path) = &result.path {
+                    prop_assert_eq!(path.first().map(String::as_str), Some(source));
+                    prop_assert_eq!(path.last().map(String::as_str), Some(target));
+                    prop_assert_eq!(path_extreme(path, widest), expected);
+                }
+            }
+
+            // The widest bottleneck is the capacity at which s and t separate
+            // once every lighter edge is removed.
+            let connected_above = |threshold: f64, inclusive: bool| {
+                let mut kept = Graph::strict();
+                for node in graph.nodes_ordered() {
+                    let _ = kept.add_node(node);
+                }
+                for edge in graph.edges_ordered() {
+                    let cap = capacity(&edge.left, &edge.right);
+                    if cap > threshold || (inclusive && cap == threshold) {
+                        kept.add_edge(edge.left, edge.right)
+                            .expect("threshold edge insertion should succeed");
+                    }
+                }
+                connected_components(&kept).components.iter().any(|component| {
+                    component.iter().any(|node| node == source)
+                        && component.iter().any(|node| node == target)
+                })
+            };
+            let widest = widest_path(&graph, source, target, "cap");
+            match widest.bottleneck {
+                Some(bottleneck) => {
+                    prop_assert!(connected_above(bottleneck, true));
+                    prop_assert!(!connected_above(bottleneck, false));
+                }
+                None => prop_assert!(!connected_above(0.0, true)),
+            }
+        }
+    }
+
+    #[test]
+    fn adjacency_matrix_round_trips_edge_sets() {
+        let mut looped = Graph::strict();
+        for (left, right) in [("a", "b"), ("b", "c"), ("c", "c")] {
+            looped
+                .add_edge(left, right)
+                .expect("edge add should succeed");
+        }
+        let mut star = Graph::strict();
+        for leaf in ["x", "y", "z"] {
+            star.add_edge("hub", leaf).expect("edge add should succeed");
+        }
+        let _ = star.add_node("isolated");
+        let fixtures = [looped, star, super::grid_2d_graph(2, 3), Graph::strict()];
+
+        for graph in &fixtures {
+            for ordering in [MatrixOrdering::GraphOrder, MatrixOrdering::SortedName] {
+                let matrix =
+                    adjacency_matrix(graph, None, ordering, DEFAULT_DENSE_MATRIX_MAX_NODES)
+                        .expect("fixture is under the cap");
+                assert_eq!(matrix.data.len(), graph.node_count() * graph.node_count());
+                let labels = matrix.labels.iter().map(String::as_str).collect::<Vec<_>>();
+                let rebuilt = from_adjacency_matrix(&labels, &matrix.data, 0.5)
+                    .expect("export is square and symmetric");
+                assert_eq!(rebuilt.node_count(), graph.node_count());
+                assert_eq!(canonical_edge_pairs(&rebuilt), canonical_edge_pairs(graph));
+            }
+        }
+
+        // The self-loop lands once on the diagonal.
+        let matrix = adjacency_matrix(&fixtures[0], None, MatrixOrdering::GraphOrder, 10)
+            .expect("fixture is under the cap");
+        assert_eq!(
+            matrix.data,
+            vec![0.0, 1.0, 0.0, 1.0, 0.0, 1.0, 0.0, 1.0, 1.0]
+        );
+    }
+
+    #[test]
+    fn incidence_matrix_of_triangle_has_known_shape() {
+        let mut triangle = Graph::strict();
+        for (left, right) in [("c", "b"), ("b", "a"), ("a", "c")] {
+            triangle
+                .add_edge(left, right)
+                .expect("edge add should succeed");
+        }
+
+        let unoriented = incidence_matrix(&triangle, false, DEFAULT_DENSE_MATRIX_MAX_NODES)
+            .expect("triangle is under the cap");
+        assert_eq!(unoriented.labels, vec!["c", "b", "a"]);
+        assert_eq!(
+            unoriented.edges,
+            vec![
+                ("a".to_owned(), "b".to_owned()),
+                ("a".to_owned(), "c".to_owned()),
+                ("b".to_owned(), "c".to_owned()),
+            ]
+        );
+        assert_eq!(unoriented.data.len(), 9);
+        let column_sum = |matrix: &IncidenceMatrix, column: usize| {
+            (0..matrix.labels.len())
+                .map(|row| matrix.data[row * matrix.edges.len() + column])
+                .sum::<f64>()
+        };
+        for column in 0..3 {
+            assert_eq!(column_sum(&unoriented, column), 2.0);
+        }
+
+        let oriented = incidence_matrix(&triangle, true, DEFAULT_DENSE_MATRIX_MAX_NODES)
+            .expect("triangle is under the cap");
+        for column in 0..3 {
+            assert_eq!(column_sum(&oriented, column), 0.0);
+        }
+        // Row "a" is the tail of both of its canonical edges.
+        assert_eq!(&oriented.data[6..9], &[-1.0, -1.0, 0.0]);
+        assert_eq!(&oriented.data[0..3], &[0.0, 1.0, 1.0]);
+
+        let mut looped = triangle.clone();
+        looped.add_edge("a", "a").expect("edge add should succeed");
+        let with_loop = incidence_matrix(&looped, false, 3).expect("still three nodes");
+        assert_eq!(with_loop.edges[0], ("a".to_owned(), "a".to_owned()));
+        assert_eq!(column_sum(&with_loop, 0), 0.0);
+    }
+
+    #[test]
+    fn adjacency_matrix_parses_weights_and_orders_rows() {
+        let mut graph = Graph::strict();
+        for (left, right, weight) in [
+            ("c", "a", CgseValue::Float(2.5)),
+            ("a", "b", CgseValue::String("4".to_owned())),
+            ("b", "c", CgseValue::Float(f64::NAN)),
+        ] {
+            let mut attrs = AttrMap::new();
+            attrs.insert("weight".to_owned(), weight);
+            graph
+                .add_edge_with_attrs(left, right, attrs)
+                .expect("edge add should succeed");
+        }
+        let _ = graph.add_node("d");
+
+        let by_insertion = adjacency_matrix(&graph, Some("weight"), MatrixOrdering::GraphOrder, 4)
+            .expect("graph is at the cap");
+        assert_eq!(by_insertion.labels, vec!["c", "a", "b", "d"]);
+        #[rustfmt::skip]
+        let expected = vec![
+            0.0, 2.5, 1.0, 0.0,
+            2.5, 0.0, 4.0, 0.0,
+            1.0, 4.0, 0.0, 0.0,
+            0.0, 0.0, 0.0, 0.0,
+        ];
+        assert_eq!(by_insertion.data, expected);
+
+        let by_name = adjacency_matrix(&graph, Some("weight"), MatrixOrdering::SortedName, 4)
+            .expect("graph is at the cap");
+        assert_eq!(by_name.labels, vec!["a", "b", "c", "d"]);
+        #[rustfmt::skip]
+        let expected = vec![
+            0.0, 4.0, 2.5, 0.0,
+            4.0, 0.0, 1.0, 0.0,
+            2.5, 1.0, 0.0, 0.0,
+            0.0, 0.0, 0.0, 0.0,
+        ];
+        assert_eq!(by_name.data, expected);
+
+        let unweighted = adjacency_matrix(&graph, None, MatrixOrdering::SortedName, 4)
+            .expect("graph is at the cap");
+        assert_eq!(&unweighted.data[0..4], &[0.0, 1.0, 1.0, 0.0]);
+
+        let rebuilt = from_adjacency_matrix(&["a", "b", "c", "d"], &by_name.data, 0.0)
+            .expect("export is square and symmetric");
+        assert_eq!(
+            rebuilt
+                .edge_attrs("a", "c")
+                .and_then(|attrs| attrs.get("weight")),
+            Some(&CgseValue::Float(2.5))
+        );
+    }
+
+    #[test]
+    fn dense_matrix_guard_rails_report_clear_errors() {
+        let grid = super::grid_2d_graph(3, 3);
+        let too_big = adjacency_matrix(&grid, None, MatrixOrdering::GraphOrder, 8);
+        assert_eq!(
+            too_big,
+            Err(MatrixError::TooManyNodes {
+                nodes: 9,
+                max_nodes: 8,
+            })
+        );
+        assert!(incidence_matrix(&grid, true, 8).is_err());
+
+        assert_eq!(
+            from_adjacency_matrix(&["a", "b"], &[0.0; 5], 0.0).err(),
+            Some(MatrixError::NotSquare { len: 5 })
+        );
+        assert_eq!(
+            from_adjacency_matrix(&["a", "b", "c"], &[0.0; 4], 0.0).err(),
+            Some(MatrixError::LabelCountMismatch {
+                labels: 3,
+                dimension: 2,
+            })
+        );
+        assert_eq!(
+            from_adjacency_matrix(&["a", "a"], &[0.0; 4], 0.0).err(),
+            Some(MatrixError::DuplicateLabel("a".to_owned()))
+        );
+        let err = from_adjacency_matrix(&["a", "b"], &[0.0, 1.0, 0.0, 0.0], 0.0)
+            .expect_err("matrix is not symmetric");
+        assert_eq!(
+            err,
+            MatrixError::Asymmetric {
+                row: "a".to_owned(),
+                column: "b".to_owned(),
+            }
+        );
+        assert!(err.to_string().contains("symmetric"));
+
+        let signed_zero = from_adjacency_matrix(&["a", "b"], &[f64::NAN, -0.0, 0.0, 2.0], 0.0)
+            .expect("signed zeros and mirrored NaN count as symmetric");
+        assert_eq!(
+            canonical_edge_pairs(&signed_zero),
+            vec![("b".to_owned(), "b".to_owned())]
+        );
+        assert!(
+            from_adjacency_matrix(&["a", "b"], &[0.0, f64::NAN, 0.0, 0.0], 0.0).is_err(),
+            "NaN against a number is asymmetric"
+        );
+    }
+
     #[test]
     fn direct_edge_shortest_path_matches_original_bfs_exactly() {
         fn original_bfs(graph: &Graph, source: &str, target: &str) -> Option<Vec<String>> {
@@ -65417,6 +68927,619 @@ mod tests {
         assert_eq!(min_result.witness.nodes_touched, 0);
     }
 
+    fn with_degree_bounds(graph: &mut Graph, node: &str, min: Option<i64>, max: Option<i64>) {
+        if let Some(min) = min {
+            with_node_weight(graph, node, "min_deg", CgseValue::Int(min));
+        }
+        if let Some(max) = max {
+            with_node_weight(graph, node, "max_deg", CgseValue::Int(max));
+        }
+    }
+
+    fn edge_weight_value(graph: &Graph, left: &str, right: &str) -> f64 {
+        graph
+            .edge_attrs(left, right)
+            .and_then(|attrs| attrs.get("weight"))
+            .and_then(CgseValue::as_f64)
+            .expect("test edges carry a weight")
+    }
+
+    /// Best objective over every edge subset meeting the `min_deg`/`max_deg` bounds.
+    fn degree_constrained_optimum_by_enumeration(
+        graph: &Graph,
+        objective: DegreeConstrainedObjective,
+    ) -> Option<f64> {
+        let edges = canonical_edge_pairs(graph);
+        let bound = |node: &str, attr: &str| {
+            graph
+                .node_attrs(node)
+                .and_then(|attrs| attrs.get(attr))
+                .and_then(CgseValue::as_f64)
+        };
+        let mut best: Option<f64> = None;
+        for mask in 0_u32..(1 << edges.len()) {
+            let mut degree = HashMap::<&str, f64>::new();
+            let mut total = 0.0;
+            for (k, (left, right)) in edges.iter().enumerate() {
+                if mask >> k & 1 == 1 {
+                    *degree.entry(left.as_str()).or_default() += 1.0;
+                    *degree.entry(right.as_str()).or_default() += 1.0;
+                    total += edge_weight_value(graph, left, right);
+                }
+            }
+            let within = graph.nodes_ordered().into_iter().all(|node| {
+                let d = degree.get(node).copied().unwrap_or(0.0);
+                bound(node, "min_deg").is_none_or(|min| d >= min)
+                    && bound(node, "max_deg").is_none_or(|max| d <= max)
+            });
+            if !within {
+                continue;
+            }
+            best = Some(match (best, objective) {
+                (None, _) => total,
+                (Some(best), DegreeConstrainedObjective::MinimizeWeight) => best.min(total),
+                (Some(best), DegreeConstrainedObjective::MaximizeWeight) => best.max(total),
+            });
+        }
+        best
+    }
+
+    #[test]
+    fn degree_constrained_subgraph_with_unit_bounds_is_a_perfect_matching() {
+        let mut graph = weighted_graph(&[
+            ("a", "b", 3.0),
+            ("b", "c", 1.0),
+            ("c", "d", 4.0),
+            ("d", "e", 1.5),
+            ("e", "f", 5.0),
+            ("f", "a", 2.0),
+        ]);
+        for node in ["a", "b", "c", "d", "e", "f"] {
+            with_degree_bounds(&mut graph, node, Some(1), Some(1));
+        }
+
+        let heaviest = degree_constrained_subgraph(
+            &graph,
+            "weight",
+            "min_deg",
+            "max_deg",
+            DegreeConstrainedObjective::MaximizeWeight,
+        );
+        let matching = max_weight_matching(&graph, true, "weight");
+        assert!(heaviest.feasible);
+        assert_eq!(heaviest.violated_node, None);
+        assert_eq!(heaviest.selected_edges, matching.matching);
+        assert!((heaviest.total_weight - matching.total_weight).abs() <= TEST_TOLERANCE);
+        assert!(heaviest.degrees.iter().all(|(_, degree)| *degree == 1));
+
+        let lightest = degree_constrained_subgraph(
+            &graph,
+            "weight",
+            "min_deg",
+            "max_deg",
+            DegreeConstrainedObjective::MinimizeWeight,
+        );
+        let matching = min_weight_matching(&graph, "weight");
+        assert!(lightest.feasible);
+        assert_eq!(lightest.selected_edges, matching.matching);
+        assert!((lightest.total_weight - matching.total_weight).abs() <= TEST_TOLERANCE);
+        assert_eq!(
+            lightest.witness.algorithm,
+            "blossom_degree_constrained_subgraph"
+        );
+    }
+
+    #[test]
+    fn degree_constrained_subgraph_reports_odd_cycle_unit_bounds_infeasible() {
+        let mut graph = weighted_graph(&[
+            ("a", "b", 1.0),
+            ("b", "c", 1.0),
+            ("c", "d", 1.0),
+            ("d", "e", 1.0),
+            ("e", "a", 1.0),
+        ]);
+        for node in ["a", "b", "c", "d", "e"] {
+            with_degree_bounds(&mut graph, node, Some(1), Some(1));
+        }
+
+        let result = degree_constrained_subgraph(
+            &graph,
+            "weight",
+            "min_deg",
+            "max_deg",
+            DegreeConstrainedObjective::MinimizeWeight,
+        );
+        assert!(!result.feasible);
+        let violated = result.violated_node.expect("a node must miss its bound");
+        let (_, degree) = result
+            .degrees
+            .iter()
+            .find(|(node, _)| *node == violated)
+            .expect("violated node has a degree");
+        assert_ne!(*degree, 1);
+        let first_miss = result
+            .degrees
+            .iter()
+            .find(|(_, degree)| *degree != 1)
+            .map(|(node, _)| node.clone());
+        assert_eq!(first_miss, Some(violated));
+
+        // A lower bound above the degree is caught the same way.
+        let mut path = weighted_graph(&[("x", "y", 1.0), ("y", "z", 1.0)]);
+        with_degree_bounds(&mut path, "z", Some(2), None);
+        let result = degree_constrained_subgraph(
+            &path,
+            "weight",
+            "min_deg",
+            "max_deg",
+            DegreeConstrainedObjective::MaximizeWeight,
+        );
+        assert!(!result.feasible);
+        assert_eq!(result.violated_node.as_deref(), Some("z"));
+    }
+
+    #[test]
+    fn degree_constrained_subgraph_matches_subset_enumeration() {
+        let base = weighted_graph(&[
+            ("a", "b", 4.0),
+            ("a", "c", -1.0),
+            ("b", "c", 2.5),
+            ("b", "d", 3.0),
+            ("c", "d", 1.0),
+            ("c", "e", 5.0),
+            ("d", "e", -2.0),
+        ]);
+        type Bounds<'a> = &'a [(&'a str, Option<i64>, Option<i64>)];
+        let cases: [Bounds; 5] = [
+            &[],
+            &[
+                ("a", Some(1), Some(2)),
+                ("b", Some(1), Some(2)),
+                ("c", Some(1), Some(2)),
+                ("d", Some(1), Some(2)),
+                ("e", Some(1), Some(2)),
+            ],
+            &[
+                ("a", Some(2), Some(2)),
+                ("c", None, Some(1)),
+                ("e", Some(1), Some(1)),
+            ],
+            &[("d", Some(3), None), ("b", None, Some(1))],
+            &[
+                ("a", Some(2), None),
+                ("b", Some(2), None),
+                ("e", None, Some(0)),
+            ],
+        ];
+        for bounds in cases {
+            let mut graph = base.clone();
+            for &(node, min, max) in bounds {
+                with_degree_bounds(&mut graph, node, min, max);
+            }
+            for objective in [
+                DegreeConstrainedObjective::MinimizeWeight,
+                DegreeConstrainedObjective::MaximizeWeight,
+            ] {
+                let result =
+                    degree_constrained_subgraph(&graph, "weight", "min_deg", "max_deg", objective);
+                let expected = degree_constrained_optimum_by_enumeration(&graph, objective);
+                assert_eq!(
+                    result.feasible,
+                    expected.is_some(),
+                    "{bounds:?} {objective:?}"
+                );
+                if let Some(expected) = expected {
+                    assert!(
+                        (result.total_weight - expected).abs() <= TEST_TOLERANCE,
+                        "{bounds:?} {objective:?}: {} vs {expected}",
+                        result.total_weight
+                    );
+                    let selected_total = result
+                        .selected_edges
+                        .iter()
+                        .map(|(left, right)| edge_weight_value(&graph, left, right))
+                        .sum::<f64>();
+                    assert!((selected_total - expected).abs() <= TEST_TOLERANCE);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn degree_constrained_subgraph_ignores_insertion_order() {
+        let edges = [
+            ("a", "b", 2.0),
+            ("a", "c", 2.0),
+            ("b", "c", 2.0),
+            ("b", "d", 1.0),
+            ("c", "d", 1.0),
+            ("d", "e", 3.0),
+            ("c", "e", 3.0),
+        ];
+        let bounds = [
+            ("a", 1, 2),
+            ("b", 1, 2),
+            ("c", 1, 2),
+            ("d", 1, 2),
+            ("e", 1, 1),
+        ];
+
+        let mut forward = weighted_graph(&edges);
+        for &(node, min, max) in &bounds {
+            with_degree_bounds(&mut forward, node, Some(min), Some(max));
+        }
+        let mut backward = Graph::strict();
+        for &(node, min, max) in bounds.iter().rev() {
+            with_degree_bounds(&mut backward, node, Some(min), Some(max));
+        }
+        let reversed = edges
+            .iter()
+            .rev()
+            .map(|&(left, right, weight)| (right, left, weight))
+            .collect::<Vec<_>>();
+        for (left, right, weight) in reversed {
+            let mut attrs = AttrMap::new();
+            attrs.insert("weight".to_owned(), CgseValue::Float(weight));
+            backward
+                .add_edge_with_attrs(left, right, attrs)
+                .expect("edge add should succeed");
+        }
+
+        for objective in [
+            DegreeConstrainedObjective::MinimizeWeight,
+            DegreeConstrainedObjective::MaximizeWeight,
+        ] {
+            let first =
+                degree_constrained_subgraph(&forward, "weight", "min_deg", "max_deg", objective);
+            let second =
+                degree_constrained_subgraph(&backward, "weight", "min_deg", "max_deg", objective);
+            assert!(first.feasible);
+            assert_eq!(second.selected_edges, first.selected_edges);
+            assert_eq!(second.total_weight, first.total_weight);
+            let mut first_degrees = first.degrees.clone();
+            let mut second_degrees = second.degrees.clone();
+            first_degrees.sort();
+            second_degrees.sort();
+            assert_eq!(second_degrees, first_degrees);
+            assert_eq!(
+                degree_constrained_subgraph(&forward, "weight", "min_deg", "max_deg", objective),
+                first
+            );
+        }
+    }
+
+    #[test]
+    fn degree_constrained_subgraph_keeps_precision_beside_a_large_component() {
+        let cycle = [
+            ("a", "b", 100_000.0),
+            ("b", "c", 100_000.4),
+            ("c", "d", 100_000.0),
+            ("d", "a", 100_000.4),
+        ];
+        let mut small = weighted_graph(&cycle);
+        for node in ["a", "b", "c", "d"] {
+            with_degree_bounds(&mut small, node, Some(1), Some(1));
+        }
+        let expected = degree_constrained_optimum_by_enumeration(
+            &small,
+            DegreeConstrainedObjective::MinimizeWeight,
+        )
+        .expect("the 4-cycle has a perfect matching");
+
+        // A 20x20 grid with default bounds shares the graph but not a component.
+        let mut graph = small.clone();
+        let cell = |row: usize, column: usize| format!("g{row}_{column}");
+        for row in 0..20 {
+            for column in 0..20 {
+                let weight = 1.0 + ((row * 20 + column) % 7) as f64;
+                for (next_row, next_column) in [(row + 1, column), (row, column + 1)] {
+                    if next_row < 20 && next_column < 20 {
+                        let mut attrs = AttrMap::new();
+                        attrs.insert("weight".to_owned(), CgseValue::Float(weight));
+                        graph
+                            .add_edge_with_attrs(
+                                cell(row, column),
+                                cell(next_row, next_column),
+                                attrs,
+                            )
+                            .expect("edge add should succeed");
+                    }
+                }
+            }
+        }
+
+        let result = degree_constrained_subgraph(
+            &graph,
+            "weight",
+            "min_deg",
+            "max_deg",
+            DegreeConstrainedObjective::MinimizeWeight,
+        );
+        assert!(result.feasible);
+        assert_eq!(
+            result.selected_edges,
+            vec![
+                ("a".to_owned(), "b".to_owned()),
+                ("c".to_owned(), "d".to_owned())
+            ]
+        );
+        assert!((result.total_weight - expected).abs() <= TEST_TOLERANCE);
+        // Only the constrained 4-cycle needs a gadget.
+        assert!(result.witness.edges_scanned < 4 * cycle.len());
+    }
+
+    fn assert_exact_total_agrees_with_float(total_weight: f64, exact: &str) {
+        let exact_as_float = exact.parse::<f64>().expect("exact total should parse");
+        assert!(
+            (total_weight - exact_as_float).abs() <= 1e-9 * exact_as_float.abs().max(1.0),
+            "f64 total {total_weight} drifted from exact total {exact}"
+        );
+    }
+
+    #[test]
+    fn max_weight_matching_with_precision_reports_exact_decimal_total() {
+        let mut graph = Graph::strict();
+        graph
+            .add_edge_with_attrs("a", "b", attrs([("weight", "0.1")]))
+            .expect("edge add should succeed");
+        graph
+            .add_edge_with_attrs("c", "d", attrs([("weight", "0.2")]))
+            .expect("edge add should succeed");
+
+        let plain = max_weight_matching(&graph, false, "weight");
+        let exact = max_weight_matching_with_precision(
+            &graph,
+            false,
+            "weight",
+            WeightPrecision::Decimal(9),
+        )
+        .expect("decimal places are supported");
+        assert_eq!(exact.matching, plain.matching);
+        assert_eq!(exact.witness, plain.witness);
+        assert_eq!(exact.total_weight.to_bits(), plain.total_weight.to_bits());
+        assert_ne!(exact.total_weight, 0.3, "f64 accumulation should drift");
+        assert_eq!(exact.total_weight_exact.as_deref(), Some("0.3"));
+        assert!(exact.weight_diagnostics.is_empty());
+        assert_exact_total_agrees_with_float(exact.total_weight, "0.3");
+
+        let float_only =
+            max_weight_matching_with_precision(&graph, false, "weight", WeightPrecision::Float)
+                .expect("decimal places are supported");
+        assert_eq!(float_only, plain);
+        assert_eq!(float_only.total_weight_exact, None);
+    }
+
+    #[test]
+    fn min_weight_matching_with_precision_keeps_blossom_selection() {
+        let mut graph = Graph::strict();
+        for (left, right, weight) in [
+            ("a", "b", "10.05"),
+            ("a", "c", "1.1"),
+            ("b", "d", "2.2"),
+            ("c", "d", "10.05"),
+        ] {
+            graph
+                .add_edge_with_attrs(left, right, single_attr("weight", weight))
+                .expect("edge add should succeed");
+        }
+
+        let plain = min_weight_matching(&graph, "weight");
+        let exact =
+            min_weight_matching_with_precision(&graph, "weight", WeightPrecision::Decimal(2))
+                .expect("decimal places are supported");
+        assert_eq!(exact.matching, plain.matching);
+        assert_eq!(
+            exact.matching,
+            vec![
+                ("a".to_owned(), "c".to_owned()),
+                ("b".to_owned(), "d".to_owned())
+            ]
+        );
+        assert_eq!(exact.total_weight_exact.as_deref(), Some("3.3"));
+        assert_exact_total_agrees_with_float(exact.total_weight, "3.3");
+    }
+
+    #[test]
+    fn shortest_path_weighted_with_precision_sums_path_exactly() {
+        let mut graph = Graph::strict();
+        graph
+            .add_edge_with_attrs("a", "b", attrs([("weight", "0.1")]))
+            .expect("edge add should succeed");
+        graph
+            .add_edge_with_attrs("b", "c", attrs([("weight", "0.2")]))
+            .expect("edge add should succeed");
+        graph
+            .add_edge_with_attrs("a", "c", attrs([("weight", "0.5")]))
+            .expect("edge add should succeed");
+
+        let plain = shortest_path_weighted(&graph, "a", "c", "weight");
+        let result = shortest_path_weighted_with_precision(
+            &graph,
+            "a",
+            "c",
+            "weight",
+            WeightPrecision::Decimal(9),
+        )
+        .expect("decimal places are supported");
+        assert_eq!(result.path, plain.path);
+        assert_eq!(result.witness, plain.witness);
+        assert_eq!(result.total_weight, Some(0.1 + 0.2));
+        assert_ne!(result.total_weight, Some(0.3));
+        assert_eq!(result.total_weight_exact.as_deref(), Some("0.3"));
+        assert_exact_total_agrees_with_float(0.1 + 0.2, "0.3");
+
+        let missing = shortest_path_weighted_with_precision(
+            &graph,
+            "a",
+            "zz",
+            "weight",
+            WeightPrecision::Decimal(9),
+        )
+        .expect("decimal places are supported");
+        assert_eq!(missing.path, None);
+        assert_eq!(missing.total_weight, None);
+        assert_eq!(missing.total_weight_exact, None);
+
+        let trivial = shortest_path_weighted_with_precision(
+            &graph,
+            "a",
+            "a",
+            "weight",
+            WeightPrecision::Decimal(9),
+        )
+        .expect("decimal places are supported");
+        assert_eq!(trivial.total_weight, Some(0.0));
+        assert_eq!(trivial.total_weight_exact.as_deref(), Some("0"));
+    }
+
+    #[test]
+    fn weight_precision_defaults_match_float_accumulator_rules() {
+        let mut graph = Graph::strict();
+        graph.add_edge("a", "b").expect("edge add should succeed");
+        graph
+            .add_edge_with_attrs("b", "c", attrs([("weight", "-2.5")]))
+            .expect("edge add should succeed");
+        graph
+            .add_edge_with_attrs("c", "d", attrs([("weight", "1.5e1")]))
+            .expect("edge add should succeed");
+        graph
+            .add_edge_with_attrs("d", "e", attrs([("weight", "nan")]))
+            .expect("edge add should succeed");
+
+        // Path costs treat negative and non-finite weights as the default 1.
+        let path = shortest_path_weighted_with_precision(
+            &graph,
+            "a",
+            "e",
+            "weight",
+            WeightPrecision::Decimal(3),
+        )
+        .expect("decimal places are supported");
+        assert_eq!(path.total_weight, Some(18.0));
+        assert_eq!(path.total_weight_exact.as_deref(), Some("18"));
+
+        // Matching totals accept any finite weight, including negatives.
+        let matching =
+            max_weight_matching_with_precision(&graph, true, "weight", WeightPrecision::Decimal(3))
+                .expect("decimal places are supported");
+        let exact = matching
+            .total_weight_exact
+            .as_deref()
+            .expect("exact total should be present");
+        assert_exact_total_agrees_with_float(matching.total_weight, exact);
+    }
+
+    #[test]
+    fn weight_precision_reports_precision_loss_and_overflow_per_edge() {
+        let mut graph = Graph::strict();
+        graph
+            .add_edge_with_attrs("a", "b", attrs([("weight", "0.1234567891")]))
+            .expect("edge add should succeed");
+        graph
+            .add_edge_with_attrs("b", "c", attrs([("weight", "0.5")]))
+            .expect("edge add should succeed");
+        graph
+            .add_edge_with_attrs("c", "d", attrs([("weight", "1e40")]))
+            .expect("edge add should succeed");
+
+        let result = shortest_path_weighted_with_precision(
+            &graph,
+            "a",
+            "d",
+            "weight",
+            WeightPrecision::Decimal(9),
+        )
+        .expect("decimal places are supported");
+        assert!(result.total_weight.is_some());
+        assert_eq!(result.total_weight_exact, None);
+        let issues = result
+            .weight_diagnostics
+            .iter()
+            .map(|diagnostic| {
+                (
+                    diagnostic.left.as_str(),
+                    diagnostic.right.as_str(),
+                    diagnostic.raw_value.as_str(),
+                    diagnostic.issue,
+                )
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            issues,
+            vec![
+                (
+                    "a",
+                    "b",
+                    "0.1234567891",
+                    WeightPrecisionIssue::PrecisionLoss
+                ),
+                ("c", "d", "1e40", WeightPrecisionIssue::Overflow),
+            ]
+        );
+
+        // Each weight fits on its own but the running total does not.
+        let mut accumulation = Graph::strict();
+        for (left, right) in [("a", "b"), ("b", "c")] {
+            accumulation
+                .add_edge_with_attrs(
+                    left,
+                    right,
+                    single_attr("weight", "100000000000000000000000000000000000000"),
+                )
+                .expect("edge add should succeed");
+        }
+        let overflowed = shortest_path_weighted_with_precision(
+            &accumulation,
+            "a",
+            "c",
+            "weight",
+            WeightPrecision::Decimal(0),
+        )
+        .expect("decimal places are supported");
+        assert_eq!(overflowed.total_weight_exact, None);
+        assert_eq!(overflowed.weight_diagnostics.len(), 1);
+        assert_eq!(overflowed.weight_diagnostics[0].left, "b");
+        assert_eq!(
+            overflowed.weight_diagnostics[0].issue,
+            WeightPrecisionIssue::Overflow
+        );
+    }
+
+    #[test]
+    fn weight_precision_rejects_unsupported_decimal_places() {
+        let graph = weighted_graph(&[("a", "b", 0.1)]);
+        let too_fine = WeightPrecision::Decimal(MAX_DECIMAL_WEIGHT_PLACES + 1);
+        let expected = Err(WeightPrecisionError::UnsupportedPlaces {
+            places: MAX_DECIMAL_WEIGHT_PLACES + 1,
+            max: MAX_DECIMAL_WEIGHT_PLACES,
+        });
+        assert_eq!(
+            max_weight_matching_with_precision(&graph, false, "weight", too_fine),
+            expected.clone()
+        );
+        assert_eq!(
+            min_weight_matching_with_precision(&graph, "weight", too_fine),
+            expected
+        );
+        assert_eq!(
+            shortest_path_weighted_with_precision(&graph, "a", "b", "weight", too_fine)
+                .err()
+                .map(|error| error.to_string())
+                .as_deref(),
+            Some("decimal weight precision supports at most 30 places, got 31")
+        );
+
+        let finest = shortest_path_weighted_with_precision(
+            &graph,
+            "a",
+            "b",
+            "weight",
+            WeightPrecision::Decimal(MAX_DECIMAL_WEIGHT_PLACES),
+        )
+        .expect("the maximum is supported");
+        assert_eq!(finest.total_weight_exact.as_deref(), Some("0.1"));
+    }
+
     #[test]
     fn minimum_spanning_tree_prim_index_state_preserves_reference_contract() {
         let empty = Graph::strict();
@@ -65616,6 +69739,166 @@ mod tests {
         assert!(left.witness_hash_id.starts_with("cgse-witness:"));
     }
 
+    fn numbered_path_graph(node_count: usize) -> Graph {
+        let mut graph = Graph::strict();
+        for index in 1..node_count {
+            graph
+                .add_edge(format!("n{}", index - 1), format!("n{index}"))
+                .expect("edge add should succeed");
+        }
+        graph
+    }
+
+    #[test]
+    fn witness_delta_stays_within_linear_bounds_after_single_edge_edit() {
+        let old_graph = numbered_path_graph(1000);
+        let mut new_graph = old_graph.clone();
+        new_graph
+            .add_edge("n0", "n999")
+            .expect("edge add should succeed");
+
+        let old = connected_components(&old_graph).witness;
+        let new = connected_components(&new_graph).witness;
+        let dominant_term = dominant_term_from_complexity_claim(&new.complexity_claim)
+            .expect("connected components claim should be recognized");
+        assert_eq!(dominant_term, "n_plus_m");
+        let bounds = expected_delta_bounds(
+            GraphSizeStats::of(&old_graph),
+            GraphSizeStats::of(&new_graph),
+            dominant_term,
+        )
+        .expect("n_plus_m is a known term");
+        assert!(
+            bounds
+                .counters
+                .iter()
+                .all(|bound| bound.max_abs_delta == 2 + 16)
+        );
+
+        let delta = assert_witness_delta_within(&old, &new, &bounds)
+            .unwrap_or_else(|report| panic!("{report}"));
+        let nodes = delta
+            .counter("nodes_touched")
+            .expect("nodes_touched is always reported");
+        assert_eq!((nodes.old, nodes.new, nodes.absolute), (1000, 1000, 0));
+        assert_eq!(nodes.relative, Some(0.0));
+    }
+
+    #[test]
+    fn witness_delta_flags_quadratic_work_against_linear_bounds() {
+        let contrived_quadratic = |graph: &Graph| ComplexityWitness {
+            algorithm: "contrived_all_pairs_scan".to_owned(),
+            complexity_claim: "O(|V| + |E|)".to_owned(),
+            nodes_touched: graph.node_count(),
+            edges_scanned: graph.node_count() * graph.node_count(),
+            queue_peak: 0,
+        };
+        let old_graph = numbered_path_graph(1000);
+        let mut new_graph = old_graph.clone();
+        new_graph
+            .add_edge("n999", "n1000")
+            .expect("edge add should succeed");
+
+        let bounds = expected_delta_bounds(
+            GraphSizeStats::of(&old_graph),
+            GraphSizeStats::of(&new_graph),
+            "n_plus_m",
+        )
+        .expect("n_plus_m is a known term");
+        let report = assert_witness_delta_within(
+            &contrived_quadratic(&old_graph),
+            &contrived_quadratic(&new_graph),
+            &bounds,
+        )
+        .expect_err("quadratic growth must exceed linear delta bounds");
+        assert!(!report.algorithm_mismatch);
+        assert_eq!(
+            report.violations,
+            vec![super::WitnessDeltaViolation {
+                counter: "edges_scanned".to_owned(),
+                absolute_delta: 2001,
+                max_abs_delta: 2 * 2 + 16,
+            }]
+        );
+        assert!(report.to_string().contains("edges_scanned moved by 2001"));
+    }
+
+    #[test]
+    fn witness_delta_reports_algorithm_mismatch_and_unknown_terms() {
+        let old = ComplexityWitness {
+            algorithm: "bfs_shortest_path".to_owned(),
+            complexity_claim: "O(|V| + |E|)".to_owned(),
+            nodes_touched: 0,
+            edges_scanned: 4,
+            queue_peak: 1,
+        };
+        let new = ComplexityWitness {
+            algorithm: "dijkstra_shortest_path".to_owned(),
+            ..old.clone()
+        };
+        let stats = GraphSizeStats { nodes: 4, edges: 4 };
+        let bounds = expected_delta_bounds(stats, stats, "n_plus_m").expect("known term");
+        let report =
+            assert_witness_delta_within(&old, &new, &bounds).expect_err("algorithms differ");
+        assert!(report.algorithm_mismatch);
+        assert!(report.violations.is_empty());
+
+        let delta = WitnessDelta::between(&old, &new);
+        assert_eq!(
+            delta
+                .counter("nodes_touched")
+                .and_then(|item| item.relative),
+            None
+        );
+        assert_eq!(expected_delta_bounds(stats, stats, "n_cubed"), None);
+        assert_eq!(dominant_term_from_complexity_claim("O(|V|^3)"), None);
+        assert_eq!(
+            dominant_term_from_complexity_claim("O(|E| log |V|)"),
+            Some("m_log_n")
+        );
+    }
+
+    #[test]
+    fn witness_delta_structs_serde_round_trip() {
+        let old_graph = numbered_path_graph(10);
+        let mut new_graph = old_graph.clone();
+        new_graph
+            .add_edge("n9", "n10")
+            .expect("edge add should succeed");
+        let old = connected_components(&old_graph).witness;
+        let mut new = connected_components(&new_graph).witness;
+        new.edges_scanned += 100;
+
+        let stats = GraphSizeStats::of(&new_graph);
+        let bounds = expected_delta_bounds(GraphSizeStats::of(&old_graph), stats, "n_plus_m")
+            .expect("known term");
+        let delta = WitnessDelta::between(&old, &new);
+        let report = assert_witness_delta_within(&old, &new, &bounds).expect_err("inflated");
+
+        let stats_json = serde_json::to_string(&stats).expect("serialize stats");
+        assert_eq!(
+            serde_json::from_str::<GraphSizeStats>(&stats_json).expect("deserialize stats"),
+            stats
+        );
+        let delta_json = serde_json::to_string(&delta).expect("serialize delta");
+        assert_eq!(
+            serde_json::from_str::<WitnessDelta>(&delta_json).expect("deserialize delta"),
+            delta
+        );
+        let bounds_json = serde_json::to_string(&bounds).expect("serialize bounds");
+        assert_eq!(
+            serde_json::from_str::<super::ExpectedDeltaBounds>(&bounds_json)
+                .expect("deserialize bounds"),
+            bounds
+        );
+        let report_json = serde_json::to_string(&report).expect("serialize report");
+        assert_eq!(
+            serde_json::from_str::<super::WitnessDeltaReport>(&report_json)
+                .expect("deserialize report"),
+            *report
+        );
+    }
+
     #[test]
     fn connected_components_are_deterministic_and_partitioned() {
         let mut graph = Graph::strict();
@@ -67113,51 +71396,20 @@ mod tests {
             "hits authority distribution must sum to one"
         );
 
-        let mut environment = BTreeMap::new();
-        environment.insert("os".to_owned(), std::env::consts::OS.to_owned());
-        environment.insert("arch".to_owned(), std::env::consts::ARCH.to_owned());
-        environment.insert(
-            "algorithm_family".to_owned(),
-            "shortest_path_first_wave".to_owned(),
-        );
-        environment.insert("source_target_pair".to_owned(), "a->e".to_owned());
-        environment.insert("strict_mode".to_owned(), "true".to_owned());
-        environment.insert("policy_row_id".to_owned(), "CGSE-POL-R08".to_owned());
-
         let replay_command = "rch exec -- cargo test -p fnx-algorithms unit_packet_005_contract_asserted -- --nocapture";
-        let log = StructuredTestLog {
-            schema_version: structured_test_log_schema_version().to_owned(),
-            run_id: "algorithms-p2c005-unit".to_owned(),
-            ts_unix_ms: 1,
-            crate_name: "fnx-algorithms".to_owned(),
-            suite_id: "unit".to_owned(),
-            packet_id: "FNX-P2C-005".to_owned(),
-            test_name: "unit_packet_005_contract_asserted".to_owned(),
-            test_id: "unit::fnx-p2c-005::contract".to_owned(),
-            test_kind: TestKind::Unit,
-            mode: CompatibilityMode::Strict,
-            fixture_id: Some("algorithms::contract::shortest_path_wave".to_owned()),
-            seed: Some(7105),
-            env_fingerprint: canonical_environment_fingerprint(&environment),
-            environment,
-            duration_ms: 7,
-            replay_command: replay_command.to_owned(),
-            artifact_refs: vec!["artifacts/conformance/latest/structured_logs.jsonl".to_owned()],
-            forensic_bundle_id: "forensics::algorithms::unit::contract".to_owned(),
-            hash_id: "sha256:algorithms-p2c005-unit".to_owned(),
-            status: TestStatus::Passed,
-            reason_code: None,
-            failure_repro: None,
-            e2e_step_traces: Vec::new(),
-            forensics_bundle_index: Some(packet_005_forensics_bundle(
-                "algorithms-p2c005-unit",
-                "unit::fnx-p2c-005::contract",
-                replay_command,
-                "forensics::algorithms::unit::contract",
-                vec!["artifacts/conformance/latest/structured_logs.jsonl".to_owned()],
-            )),
-        };
-        log.validate()
+        StructuredLogBuilder::new("algorithms-p2c005-unit", "unit::fnx-p2c-005::contract")
+            .with_packet_id("FNX-P2C-005")
+            .with_test_name("unit_packet_005_contract_asserted")
+            .with_fixture_id("algorithms::contract::shortest_path_wave")
+            .with_seed(7105)
+            .with_env("algorithm_family", "shortest_path_first_wave")
+            .with_env("source_target_pair", "a->e")
+            .with_env("strict_mode", "true")
+            .with_env("policy_row_id", "CGSE-POL-R08")
+            .with_timing(1, 7)
+            .with_replay_command(replay_command)
+            .with_forensic_bundle_id("forensics::algorithms::unit::contract")
+            .build()
             .expect("unit packet-005 telemetry log should satisfy strict schema");
     }
 
@@ -67471,56 +71723,32 @@ mod tests {
                     .wrapping_add((*left_edge as u64) << 8)
                     .wrapping_add(*right_edge as u64)
             });
-            let mut environment = BTreeMap::new();
-            environment.insert("os".to_owned(), std::env::consts::OS.to_owned());
-            environment.insert("arch".to_owned(), std::env::consts::ARCH.to_owned());
-            environment.insert("graph_fingerprint".to_owned(), graph_fingerprint(&graph));
-            environment.insert("tie_break_policy".to_owned(), "lexical_neighbor_order".to_owned());
-            environment.insert("invariant_id".to_owned(), "P2C005-INV-1".to_owned());
-            environment.insert("policy_row_id".to_owned(), "CGSE-POL-R08".to_owned());
-
             let replay_command =
                 "rch exec -- cargo test -p fnx-algorithms property_packet_005_invariants -- --nocapture";
-            let log = StructuredTestLog {
-                schema_version: structured_test_log_schema_version().to_owned(),
-                run_id: "algorithms-p2c005-property".to_owned(),
-                ts_unix_ms: 2,
-                crate_name: "fnx-algorithms".to_owned(),
-                suite_id: "property".to_owned(),
-                packet_id: "FNX-P2C-005".to_owned(),
-                test_name: "property_packet_005_invariants".to_owned(),
-                test_id: "property::fnx-p2c-005::invariants".to_owned(),
-                test_kind: TestKind::Property,
-                mode: CompatibilityMode::Hardened,
-                fixture_id: Some("algorithms::property::path_and_centrality_matrix".to_owned()),
-                seed: Some(deterministic_seed),
-                env_fingerprint: canonical_environment_fingerprint(&environment),
-                environment,
-                duration_ms: 12,
-                replay_command: replay_command.to_owned(),
-                artifact_refs: vec![
-                    "artifacts/conformance/latest/structured_log_emitter_normalization_report.json"
-                        .to_owned(),
-                ],
-                forensic_bundle_id: "forensics::algorithms::property::invariants".to_owned(),
-                hash_id: "sha256:algorithms-p2c005-property".to_owned(),
-                status: TestStatus::Passed,
-                reason_code: None,
-                failure_repro: None,
-                e2e_step_traces: Vec::new(),
-                forensics_bundle_index: Some(packet_005_forensics_bundle(
-                    "algorithms-p2c005-property",
-                    "property::fnx-p2c-005::invariants",
-                    replay_command,
-                    "forensics::algorithms::property::invariants",
-                    vec![
-                        "artifacts/conformance/latest/structured_log_emitter_normalization_report.json"
-                            .to_owned(),
-                    ],
-                )),
-            };
+            let log = StructuredLogBuilder::new(
+                "algorithms-p2c005-property",
+                "property::fnx-p2c-005::invariants",
+            )
+            .with_packet_id("FNX-P2C-005")
+            .with_test_name("property_packet_005_invariants")
+            .with_test_kind(TestKind::Property)
+            .with_mode(CompatibilityMode::Hardened)
+            .with_fixture_id("algorithms::property::path_and_centrality_matrix")
+            .with_seed(deterministic_seed)
+            .with_env("graph_fingerprint", graph_fingerprint(&graph))
+            .with_env("tie_break_policy", "lexical_neighbor_order")
+            .with_env("invariant_id", "P2C005-INV-1")
+            .with_env("policy_row_id", "CGSE-POL-R08")
+            .with_timing(2, 12)
+            .with_replay_command(replay_command)
+            .with_artifact_refs(vec![
+                "artifacts/conformance/latest/structured_log_emitter_normalization_report.json"
+                    .to_owned(),
+            ])
+            .with_forensic_bundle_id("forensics::algorithms::property::invariants")
+            .build();
             prop_assert!(
-                log.validate().is_ok(),
+                log.is_ok(),
                 "packet-005 property telemetry log should satisfy strict schema"
             );
         }
@@ -68026,60 +72254,33 @@ mod tests {
                     .fold(0_u64, |acc, noise| acc.wrapping_mul(17).wrapping_add(*noise as u64))
             );
 
-            let mut environment = BTreeMap::new();
-            environment.insert("os".to_owned(), std::env::consts::OS.to_owned());
-            environment.insert("arch".to_owned(), std::env::consts::ARCH.to_owned());
-            environment.insert("graph_fingerprint".to_owned(), graph_fingerprint(&forward));
-            environment.insert("tie_break_policy".to_owned(), "lexical_neighbor_order".to_owned());
-            environment.insert("invariant_id".to_owned(), "P2C005-INV-2".to_owned());
-            environment.insert("policy_row_id".to_owned(), "CGSE-POL-R08".to_owned());
-            environment.insert(
-                "perturbation_model".to_owned(),
-                "reverse_insertion_plus_noise_nodes".to_owned(),
-            );
-
             let replay_command =
                 "rch exec -- cargo test -p fnx-algorithms property_packet_005_insertion_permutation_and_noise_are_replay_stable -- --nocapture";
-            let log = StructuredTestLog {
-                schema_version: structured_test_log_schema_version().to_owned(),
-                run_id: "algorithms-p2c005-property-perturbation".to_owned(),
-                ts_unix_ms: 3,
-                crate_name: "fnx-algorithms".to_owned(),
-                suite_id: "property".to_owned(),
-                packet_id: "FNX-P2C-005".to_owned(),
-                test_name: "property_packet_005_insertion_permutation_and_noise_are_replay_stable".to_owned(),
-                test_id: "property::fnx-p2c-005::invariants".to_owned(),
-                test_kind: TestKind::Property,
-                mode: CompatibilityMode::Hardened,
-                fixture_id: Some("algorithms::property::permutation_noise_matrix".to_owned()),
-                seed: Some(deterministic_seed),
-                env_fingerprint: canonical_environment_fingerprint(&environment),
-                environment,
-                duration_ms: 15,
-                replay_command: replay_command.to_owned(),
-                artifact_refs: vec![
-                    "artifacts/conformance/latest/structured_log_emitter_normalization_report.json"
-                        .to_owned(),
-                ],
-                forensic_bundle_id: "forensics::algorithms::property::permutation_noise".to_owned(),
-                hash_id: "sha256:algorithms-p2c005-property-permutation".to_owned(),
-                status: TestStatus::Passed,
-                reason_code: None,
-                failure_repro: None,
-                e2e_step_traces: Vec::new(),
-                forensics_bundle_index: Some(packet_005_forensics_bundle(
-                    "algorithms-p2c005-property-perturbation",
-                    "property::fnx-p2c-005::invariants",
-                    replay_command,
-                    "forensics::algorithms::property::permutation_noise",
-                    vec![
-                        "artifacts/conformance/latest/structured_log_emitter_normalization_report.json"
-                            .to_owned(),
-                    ],
-                )),
-            };
+            let log = StructuredLogBuilder::new(
+                "algorithms-p2c005-property-perturbation",
+                "property::fnx-p2c-005::invariants",
+            )
+            .with_packet_id("FNX-P2C-005")
+            .with_test_name("property_packet_005_insertion_permutation_and_noise_are_replay_stable")
+            .with_test_kind(TestKind::Property)
+            .with_mode(CompatibilityMode::Hardened)
+            .with_fixture_id("algorithms::property::permutation_noise_matrix")
+            .with_seed(deterministic_seed)
+            .with_env("graph_fingerprint", graph_fingerprint(&forward))
+            .with_env("tie_break_policy", "lexical_neighbor_order")
+            .with_env("invariant_id", "P2C005-INV-2")
+            .with_env("policy_row_id", "CGSE-POL-R08")
+            .with_env("perturbation_model", "reverse_insertion_plus_noise_nodes")
+            .with_timing(3, 15)
+            .with_replay_command(replay_command)
+            .with_artifact_refs(vec![
+                "artifacts/conformance/latest/structured_log_emitter_normalization_report.json"
+                    .to_owned(),
+            ])
+            .with_forensic_bundle_id("forensics::algorithms::property::permutation_noise")
+            .build();
             prop_assert!(
-                log.validate().is_ok(),
+                log.is_ok(),
                 "packet-005 perturbation telemetry log should satisfy strict schema"
             );
         }
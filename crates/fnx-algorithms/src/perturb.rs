@@ -0,0 +1,462 @@
+//! Deterministic, seeded graph perturbations for robustness testing.
+//!
+//! Every mutator returns a perturbed copy and leaves its input untouched. The
+//! node set, node attributes and the attributes of surviving edges carry over;
+//! only edges change. Randomness comes from the crate's CPython-compatible
+//! MT19937 stream, and candidates are always drawn from canonically ordered
+//! node and edge lists (`left <= right`, sorted by name), so one seed yields
+//! the same perturbed edge set on every run and for every insertion order of
+//! the same graph.
+//!
+//! [`perturbation_sensitivity`] replays a mutator over seeded trials and
+//! summarizes how far a per-node metric moves from its unperturbed baseline.
+
+use std::collections::{HashMap, HashSet};
+
+use fnx_classes::Graph;
+use serde::{Deserialize, Serialize};
+
+use super::{ApproxRandom, CentralityScore};
+
+/// Swap attempts allowed per requested swap before `rewire_edges` gives up,
+/// matching the `max_tries` budget of `networkx.double_edge_swap`.
+const REWIRE_TRIES_PER_SWAP: usize = 100;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct NodeSensitivity {
+    pub node: String,
+    pub baseline: f64,
+    /// Mean score over the perturbed trials.
+    pub mean: f64,
+    /// Population variance of the score over the perturbed trials.
+    pub variance: f64,
+    /// Largest `|score - baseline|` seen in any trial.
+    pub max_deviation: f64,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PerturbationSensitivityReport {
+    pub trials: usize,
+    pub seed: u64,
+    /// One entry per node scored on the unperturbed graph, in that order.
+    pub nodes: Vec<NodeSensitivity>,
+}
+
+fn canonical_edges(graph: &Graph) -> Vec<(String, String)> {
+    let mut edges = graph
+        .edges_ordered()
+        .into_iter()
+        .map(|edge| {
+            if edge.left <= edge.right {
+                (edge.left, edge.right)
+            } else {
+                (edge.right, edge.left)
+            }
+        })
+        .collect::<Vec<_>>();
+    edges.sort_unstable();
+    edges
+}
+
+/// `round(fraction * total)`, with `fraction` clamped to `[0, 1]` and NaN as 0.
+fn fraction_of(total: usize, fraction: f64) -> usize {
+    if fraction.is_nan() {
+        return 0;
+    }
+    ((fraction.clamp(0.0, 1.0) * total as f64).round() as usize).min(total)
+}
+
+/// Moves `count` uniformly chosen items to the front of `items` (partial
+/// Fisher-Yates) and returns that prefix.
+fn choose_prefix<'a, T>(items: &'a mut [T], count: usize, rng: &mut ApproxRandom) -> &'a [T] {
+    for slot in 0..count {
+        let pick = slot + rng.randbelow(items.len() - slot);
+        items.swap(slot, pick);
+    }
+    &items[..count]
+}
+
+/// Removes `round(fraction * |E|)` uniformly chosen edges.
+#[must_use]
+pub fn remove_random_edges(graph: &Graph, fraction: f64, seed: u64) -> Graph {
+    let mut edges = canonical_edges(graph);
+    let count = fraction_of(edges.len(), fraction);
+    let mut rng = ApproxRandom::new(seed);
+    let mut perturbed = graph.clone();
+    for (left, right) in choose_prefix(&mut edges, count, &mut rng) {
+        perturbed.remove_edge(left, right);
+    }
+    perturbed
+}
+
+/// Adds `count` uniformly chosen new edges between distinct, non-adjacent
+/// nodes, or every such pair when fewer than `count` exist. New edges carry no
+/// attributes.
+#[must_use]
+pub fn add_random_edges(graph: &Graph, count: usize, seed: u64) -> Graph {
+    let mut nodes = graph.nodes_ordered();
+    nodes.sort_unstable();
+    let n = nodes.len();
+    let existing = canonical_edges(graph)
+        .into_iter()
+        .filter(|(left, right)| left != right)
+        .collect::<HashSet<_>>();
+    let available = n * n.saturating_sub(1) / 2 - existing.len();
+    let count = count.min(available);
+    let mut rng = ApproxRandom::new(seed);
+    let mut perturbed = graph.clone();
+
+    if count.saturating_mul(2) >= available {
+        // Dense request: enumerate the non-edges and sample among them.
+        let mut candidates = Vec::with_capacity(available);
+        for (i, left) in nodes.iter().enumerate() {
+            for right in &nodes[i + 1..] {
+                if !existing.contains(&((*left).to_owned(), (*right).to_owned())) {
+                    candidates.push((*left, *right));
+                }
+            }
+        }
+        for (left, right) in choose_prefix(&mut candidates, count, &mut rng) {
+            let _ = perturbed.add_edge(*left, *right);
+        }
+        return perturbed;
+    }
+
+    // Sparse request: rejection-sample node pairs; at least half of all pairs
+    // stay free throughout, so each draw succeeds with probability >= 1/2.
+    let mut added = HashSet::with_capacity(count);
+    while added.len() < count {
+        let (a, b) = (rng.randbelow(n), rng.randbelow(n));
+        if a == b {
+            continue;
+        }
+        let pair = (nodes[a.min(b)].to_owned(), nodes[a.max(b)].to_owned());
+        if existing.contains(&pair) || added.contains(&pair) {
+            continue;
+        }
+        let _ = perturbed.add_edge(&pair.0, &pair.1);
+        added.insert(pair);
+    }
+    perturbed
+}
+
+/// Degree-preserving rewiring by `round(fraction * |E|)` double-edge swaps:
+/// `u-v, x-y` becomes `u-x, v-y`, each new edge inheriting the attributes of
+/// the edge it replaces. Swaps that would create a self-loop or a duplicate
+/// edge are rejected; after `100` attempts per requested swap the remaining
+/// swaps are skipped, so tightly constrained graphs get fewer swaps. Self-loops
+/// are never rewired.
+#[must_use]
+pub fn rewire_edges(graph: &Graph, fraction: f64, seed: u64) -> Graph {
+    let mut edges = canonical_edges(graph)
+        .into_iter()
+        .filter(|(left, right)| left != right)
+        .collect::<Vec<_>>();
+    let swaps = fraction_of(edges.len(), fraction);
+    let mut rng = ApproxRandom::new(seed);
+    let mut perturbed = graph.clone();
+    if edges.len() < 2 {
+        return perturbed;
+    }
+
+    let mut done = 0usize;
+    let mut tries = 0usize;
+    while done < swaps && tries < swaps * REWIRE_TRIES_PER_SWAP {
+        tries += 1;
+        let (i, j) = (rng.randbelow(edges.len()), rng.randbelow(edges.len()));
+        if i == j {
+            continue;
+        }
+        let (u, v) = edges[i].clone();
+        let (mut x, mut y) = edges[j].clone();
+        if rng.randbelow(2) == 1 {
+            std::mem::swap(&mut x, &mut y);
+        }
+        if u == x || v == y || perturbed.has_edge(&u, &x) || perturbed.has_edge(&v, &y) {
+            continue;
+        }
+        let first_attrs = perturbed.edge_attrs(&u, &v).cloned().unwrap_or_default();
+        let second_attrs = perturbed.edge_attrs(&x, &y).cloned().unwrap_or_default();
+        perturbed.remove_edge(&u, &v);
+        perturbed.remove_edge(&x, &y);
+        let _ = perturbed.add_edge_with_attrs(u.clone(), x.clone(), first_attrs);
+        let _ = perturbed.add_edge_with_attrs(v.clone(), y.clone(), second_attrs);
+        edges[i] = if u <= x { (u, x) } else { (x, u) };
+        edges[j] = if v <= y { (v, y) } else { (y, v) };
+        done += 1;
+    }
+    perturbed
+}
+
+/// Runs `mutator` for `trials` seeded trials and reports, per node, how the
+/// `metric` scores spread around their unperturbed baseline.
+///
+/// Trial `t` calls `mutator(graph, seed + t)` (wrapping), so any single trial
+/// can be replayed on its own. A node the metric omits in some trial scores
+/// 0.0 there. With `trials == 0` the mean is the baseline and the spread zero.
+pub fn perturbation_sensitivity(
+    graph: &Graph,
+    metric: impl Fn(&Graph) -> Vec<CentralityScore>,
+    mutator: impl Fn(&Graph, u64) -> Graph,
+    trials: usize,
+    seed: u64,
+) -> PerturbationSensitivityReport {
+    let baseline = metric(graph);
+    let position = baseline
+        .iter()
+        .enumerate()
+        .map(|(idx, entry)| (entry.node.as_str(), idx))
+        .collect::<HashMap<_, _>>();
+    let mut samples = vec![Vec::with_capacity(trials); baseline.len()];
+    for trial in 0..trials {
+        let perturbed = mutator(graph, seed.wrapping_add(trial as u64));
+        let mut scores = vec![0.0; baseline.len()];
+        for entry in metric(&perturbed) {
+            if let Some(&idx) = position.get(entry.node.as_str()) {
+                scores[idx] = entry.score;
+            }
+        }
+        for (series, score) in samples.iter_mut().zip(scores) {
+            series.push(score);
+        }
+    }
+
+    let nodes = baseline
+        .iter()
+        .zip(&samples)
+        .map(|(entry, series)| {
+            if series.is_empty() {
+                return NodeSensitivity {
+                    node: entry.node.clone(),
+                    baseline: entry.score,
+                    mean: entry.score,
+                    variance: 0.0,
+                    max_deviation: 0.0,
+                };
+            }
+            let count = series.len() as f64;
+            let mean = series.iter().sum::<f64>() / count;
+            let variance = series
+                .iter()
+                .map(|score| (score - mean) * (score - mean))
+                .sum::<f64>()
+                / count;
+            let max_deviation = series
+                .iter()
+                .map(|score| (score - entry.score).abs())
+                .fold(0.0, f64::max);
+            NodeSensitivity {
+                node: entry.node.clone(),
+                baseline: entry.score,
+                mean,
+                variance,
+                max_deviation,
+            }
+        })
+        .collect();
+
+    PerturbationSensitivityReport {
+        trials,
+        seed,
+        nodes,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{degree_centrality, pagerank};
+
+    type Mutator = fn(&Graph, u64) -> Graph;
+
+    fn graph_from(edges: &[(&str, &str)]) -> Graph {
+        let mut graph = Graph::strict();
+        for (left, right) in edges {
+            graph
+                .add_edge(*left, *right)
+                .expect("edge add should succeed");
+        }
+        graph
+    }
+
+    fn sample_edges() -> Vec<(&'static str, &'static str)> {
+        vec![
+            ("a", "b"),
+            ("b", "c"),
+            ("c", "d"),
+            ("d", "a"),
+            ("a", "e"),
+            ("e", "f"),
+            ("f", "b"),
+            ("c", "g"),
+            ("g", "h"),
+            ("h", "d"),
+        ]
+    }
+
+    fn sorted_degrees(graph: &Graph) -> Vec<(String, usize)> {
+        let mut degrees = graph
+            .nodes_ordered()
+            .into_iter()
+            .map(|node| (node.to_owned(), graph.degree(node)))
+            .collect::<Vec<_>>();
+        degrees.sort_unstable();
+        degrees
+    }
+
+    #[test]
+    fn removing_no_edges_changes_nothing() {
+        let graph = graph_from(&sample_edges());
+        let untouched = remove_random_edges(&graph, 0.0, 7);
+        assert_eq!(canonical_edges(&untouched), canonical_edges(&graph));
+        assert_eq!(untouched.nodes_ordered(), graph.nodes_ordered());
+
+        let report = perturbation_sensitivity(
+            &graph,
+            |g| degree_centrality(g).scores,
+            |g, seed| remove_random_edges(g, 0.0, seed),
+            5,
+            11,
+        );
+        for entry in &report.nodes {
+            assert_eq!(entry.mean, entry.baseline);
+            assert_eq!(entry.variance, 0.0);
+            assert_eq!(entry.max_deviation, 0.0);
+        }
+    }
+
+    #[test]
+    fn removing_every_edge_yields_empty_graph_metrics() {
+        let graph = graph_from(&sample_edges());
+        let stripped = remove_random_edges(&graph, 1.0, 3);
+        assert_eq!(stripped.edge_count(), 0);
+        assert_eq!(stripped.nodes_ordered(), graph.nodes_ordered());
+
+        let mut edgeless = Graph::strict();
+        for node in graph.nodes_ordered() {
+            let _ = edgeless.add_node(node);
+        }
+        assert_eq!(pagerank(&stripped).scores, pagerank(&edgeless).scores);
+        assert!(
+            degree_centrality(&stripped)
+                .scores
+                .iter()
+                .all(|entry| entry.score == 0.0)
+        );
+    }
+
+    #[test]
+    fn add_random_edges_avoids_duplicates_and_self_loops() {
+        let graph = graph_from(&sample_edges());
+        let grown = add_random_edges(&graph, 6, 5);
+        assert_eq!(grown.edge_count(), graph.edge_count() + 6);
+        for (left, right) in canonical_edges(&grown) {
+            assert_ne!(left, right);
+        }
+        for (left, right) in canonical_edges(&graph) {
+            assert!(grown.has_edge(&left, &right));
+        }
+
+        // Asking for more than the free pairs completes the graph.
+        let complete = add_random_edges(&graph, 1_000, 5);
+        assert_eq!(complete.edge_count(), 8 * 7 / 2);
+    }
+
+    #[test]
+    fn rewiring_preserves_the_degree_sequence() {
+        let graph = graph_from(&sample_edges());
+        for seed in 0..5 {
+            let rewired = rewire_edges(&graph, 0.5, seed);
+            assert_eq!(sorted_degrees(&rewired), sorted_degrees(&graph));
+            assert_eq!(rewired.edge_count(), graph.edge_count());
+        }
+        assert_ne!(
+            canonical_edges(&rewire_edges(&graph, 1.0, 1)),
+            canonical_edges(&graph)
+        );
+    }
+
+    #[test]
+    fn pagerank_sensitivity_on_star_is_largest_at_the_hub() {
+        let leaves = (0..20).map(|leaf| format!("l{leaf}")).collect::<Vec<_>>();
+        let star = graph_from(
+            &leaves
+                .iter()
+                .map(|leaf| ("hub", leaf.as_str()))
+                .collect::<Vec<_>>(),
+        );
+        let report = perturbation_sensitivity(
+            &star,
+            |g| pagerank(g).scores,
+            // Leaf-to-leaf shortcuts drain the hub in every trial but touch
+            // any one leaf only occasionally.
+            |g, seed| add_random_edges(g, (seed % 5 + 1) as usize, seed),
+            20,
+            42,
+        );
+        assert_eq!(report.trials, 20);
+        let hub = report
+            .nodes
+            .iter()
+            .find(|entry| entry.node == "hub")
+            .expect("hub is scored");
+        for leaf in report.nodes.iter().filter(|entry| entry.node != "hub") {
+            assert!(
+                hub.variance > leaf.variance,
+                "{} out-varied the hub",
+                leaf.node
+            );
+        }
+        assert!(hub.max_deviation > 0.0);
+    }
+
+    #[test]
+    fn perturbations_are_deterministic_across_runs_and_insertion_orders() {
+        let forward = graph_from(&sample_edges());
+        let backward = graph_from(
+            &sample_edges()
+                .into_iter()
+                .rev()
+                .map(|(left, right)| (right, left))
+                .collect::<Vec<_>>(),
+        );
+        let mutators: [(&str, Mutator); 3] = [
+            ("remove", |g, seed| remove_random_edges(g, 0.4, seed)),
+            ("add", |g, seed| add_random_edges(g, 4, seed)),
+            ("rewire", |g, seed| rewire_edges(g, 0.6, seed)),
+        ];
+        for seed in [0, 9, u64::MAX] {
+            for (label, mutate) in mutators {
+                let first = canonical_edges(&mutate(&forward, seed));
+                assert_eq!(canonical_edges(&mutate(&forward, seed)), first, "{label}");
+                assert_eq!(canonical_edges(&mutate(&backward, seed)), first, "{label}");
+            }
+        }
+
+        let report = |graph: &Graph| {
+            perturbation_sensitivity(
+                graph,
+                |g| degree_centrality(g).scores,
+                |g, seed| remove_random_edges(g, 0.3, seed),
+                8,
+                17,
+            )
+        };
+        let first = report(&forward);
+        assert_eq!(report(&forward), first);
+        let json = serde_json::to_string(&first).expect("report serializes");
+        assert_eq!(
+            serde_json::to_string(&report(&forward)).expect("report serializes"),
+            json
+        );
+        let decoded: PerturbationSensitivityReport =
+            serde_json::from_str(&json).expect("report deserializes");
+        assert_eq!(decoded.nodes.len(), first.nodes.len());
+
+        let mut mirrored = report(&backward).nodes;
+        mirrored.sort_by(|a, b| a.node.cmp(&b.node));
+        let mut expected = first.nodes;
+        expected.sort_by(|a, b| a.node.cmp(&b.node));
+        assert_eq!(mirrored, expected);
+    }
+}
@@ -0,0 +1,662 @@
+//! Reusable end-to-end scenario builders for conformance packets.
+//!
+//! Enabled by the `test_support` feature (and always compiled for this
+//! crate's own tests). A scenario runs in three steps:
+//!
+//! 1. [`ScenarioFixture::new`] builds a small named graph from a fixed catalog.
+//! 2. [`WitnessedRun::execute`] runs one algorithm on it and derives the
+//!    result's [`CgseWitnessArtifact`].
+//! 3. [`StructuredLogBuilder`] turns the run into a validated
+//!    [`StructuredTestLog`].
+//!
+//! Nothing reads the clock, so every output is replay-stable.
+
+use std::collections::BTreeMap;
+use std::fmt;
+
+use fnx_classes::{AttrMap, Graph};
+use fnx_runtime::{
+    CgseValue, CompatibilityMode, ForensicsBundleIndex, StructuredTestLog, TestKind, TestStatus,
+    canonical_environment_fingerprint, structured_test_log_schema_version,
+};
+use serde::{Deserialize, Serialize};
+
+use super::{
+    CentralityScore, CgseWitnessArtifact, ComplexityWitness, bridges, closeness_centrality,
+    connected_components, degree_centrality, pagerank, shortest_path_unweighted,
+    shortest_path_weighted, stable_hash_hex,
+};
+
+/// Fixture names accepted by [`ScenarioFixture::new`].
+pub const SCENARIO_FIXTURE_CATALOG: [&str; 5] = [
+    "path",
+    "cycle",
+    "star",
+    "two_triangles_bridge",
+    "weighted_diamond",
+];
+
+/// Algorithm names accepted by [`WitnessedRun::execute`].
+pub const SCENARIO_ALGORITHMS: [&str; 7] = [
+    "shortest_path_unweighted",
+    "shortest_path_weighted",
+    "degree_centrality",
+    "closeness_centrality",
+    "pagerank",
+    "connected_components",
+    "bridges",
+];
+
+/// Edge attribute the `weighted_diamond` fixture stores its weights under.
+pub const SCENARIO_WEIGHT_ATTR: &str = "weight";
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ScenarioError {
+    UnknownFixture(String),
+    UnknownAlgorithm(String),
+}
+
+impl fmt::Display for ScenarioError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnknownFixture(name) => write!(
+                f,
+                "unknown scenario fixture {name}; expected one of {SCENARIO_FIXTURE_CATALOG:?}"
+            ),
+            Self::UnknownAlgorithm(name) => write!(
+                f,
+                "unknown scenario algorithm {name}; expected one of {SCENARIO_ALGORITHMS:?}"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for ScenarioError {}
+
+/// A catalog graph plus the endpoints path algorithms run between.
+#[derive(Debug, Clone)]
+pub struct ScenarioFixture {
+    pub name: String,
+    pub graph: Graph,
+    pub source: String,
+    pub target: String,
+}
+
+impl ScenarioFixture {
+    /// Builds the catalog fixture `name`:
+    ///
+    /// - `path`: `a-b-c-d-e`, from `a` to `e`.
+    /// - `cycle`: `a-b-c-d-e-f-a`, from `a` to `d`.
+    /// - `star`: `hub` joined to `a`..`d`, from `a` to `d`.
+    /// - `two_triangles_bridge`: triangles `abc` and `def` bridged by `c-d`,
+    ///   from `a` to `f`.
+    /// - `weighted_diamond`: `a-b` 1, `a-c` 4, `b-c` 2, `b-d` 5, `c-d` 1, from
+    ///   `a` to `d`. The lightest route `a-b-c-d` is not the fewest-hop one.
+    pub fn new(name: &str) -> Result<Self, ScenarioError> {
+        let (edges, source, target): (&[(&str, &str, f64)], &str, &str) = match name {
+            "path" => (
+                &[
+                    ("a", "b", 1.0),
+                    ("b", "c", 1.0),
+                    ("c", "d", 1.0),
+                    ("d", "e", 1.0),
+                ],
+                "a",
+                "e",
+            ),
+            "cycle" => (
+                &[
+                    ("a", "b", 1.0),
+                    ("b", "c", 1.0),
+                    ("c", "d", 1.0),
+                    ("d", "e", 1.0),
+                    ("e", "f", 1.0),
+                    ("f", "a", 1.0),
+                ],
+                "a",
+                "d",
+            ),
+            "star" => (
+                &[
+                    ("hub", "a", 1.0),
+                    ("hub", "b", 1.0),
+                    ("hub", "c", 1.0),
+                    ("hub", "d", 1.0),
+                ],
+                "a",
+                "d",
+            ),
+            "two_triangles_bridge" => (
+                &[
+                    ("a", "b", 1.0),
+                    ("b", "c", 1.0),
+                    ("c", "a", 1.0),
+                    ("c", "d", 1.0),
+                    ("d", "e", 1.0),
+                    ("e", "f", 1.0),
+                    ("f", "d", 1.0),
+                ],
+                "a",
+                "f",
+            ),
+            "weighted_diamond" => (
+                &[
+                    ("a", "b", 1.0),
+                    ("a", "c", 4.0),
+                    ("b", "c", 2.0),
+                    ("b", "d", 5.0),
+                    ("c", "d", 1.0),
+                ],
+                "a",
+                "d",
+            ),
+            _ => return Err(ScenarioError::UnknownFixture(name.to_owned())),
+        };
+
+        let mut graph = Graph::strict();
+        for &(left, right, weight) in edges {
+            let mut attrs = AttrMap::new();
+            attrs.insert(SCENARIO_WEIGHT_ATTR.to_owned(), CgseValue::Float(weight));
+            graph
+                .add_edge_with_attrs(left, right, attrs)
+                .expect("catalog edges are valid in a strict graph");
+        }
+        Ok(Self {
+            name: name.to_owned(),
+            graph,
+            source: source.to_owned(),
+            target: target.to_owned(),
+        })
+    }
+
+    /// `algorithms::fixture::<name>`, the id structured logs record.
+    #[must_use]
+    pub fn fixture_id(&self) -> String {
+        format!("algorithms::fixture::{}", self.name)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum ScenarioOutput {
+    Path(Option<Vec<String>>),
+    Scores(Vec<CentralityScore>),
+    Components(Vec<Vec<String>>),
+    Edges(Vec<(String, String)>),
+}
+
+/// One algorithm run on a fixture, with its witness and CGSE artifact.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct WitnessedRun {
+    pub fixture_id: String,
+    pub algorithm: String,
+    pub algorithm_family: String,
+    pub output: ScenarioOutput,
+    pub witness: ComplexityWitness,
+    pub artifact: CgseWitnessArtifact,
+}
+
+impl WitnessedRun {
+    /// Runs one of [`SCENARIO_ALGORITHMS`] on `fixture`. Path algorithms go
+    /// from `fixture.source` to `fixture.target`; `shortest_path_weighted`
+    /// reads [`SCENARIO_WEIGHT_ATTR`]. The artifact's operation is the
+    /// algorithm name.
+    pub fn execute(fixture: &ScenarioFixture, algorithm: &str) -> Result<Self, ScenarioError> {
+        let graph = &fixture.graph;
+        let (family, output, witness) = match algorithm {
+            "shortest_path_unweighted" => {
+                let result = shortest_path_unweighted(graph, &fixture.source, &fixture.target);
+                (
+                    "shortest_path",
+                    ScenarioOutput::Path(result.path),
+                    result.witness,
+                )
+            }
+            "shortest_path_weighted" => {
+                let result = shortest_path_weighted(
+                    graph,
+                    &fixture.source,
+                    &fixture.target,
+                    SCENARIO_WEIGHT_ATTR,
+                );
+                (
+                    "shortest_path",
+                    ScenarioOutput::Path(result.path),
+                    result.witness,
+                )
+            }
+            "degree_centrality" => {
+                let result = degree_centrality(graph);
+                (
+                    "centrality",
+                    ScenarioOutput::Scores(result.scores),
+                    result.witness,
+                )
+            }
+            "closeness_centrality" => {
+                let result = closeness_centrality(graph);
+                (
+                    "centrality",
+                    ScenarioOutput::Scores(result.scores),
+                    result.witness,
+                )
+            }
+            "pagerank" => {
+                let result = pagerank(graph);
+                (
+                    "centrality",
+                    ScenarioOutput::Scores(result.scores),
+                    result.witness,
+                )
+            }
+            "connected_components" => {
+                let result = connected_components(graph);
+                (
+                    "connectivity",
+                    ScenarioOutput::Components(result.components),
+                    result.witness,
+                )
+            }
+            "bridges" => {
+                let result = bridges(graph);
+                (
+                    "connectivity",
+                    ScenarioOutput::Edges(result.edges),
+                    result.witness,
+                )
+            }
+            _ => return Err(ScenarioError::UnknownAlgorithm(algorithm.to_owned())),
+        };
+        let artifact = witness.to_cgse_witness_artifact(family, algorithm, &[]);
+        Ok(Self {
+            fixture_id: fixture.fixture_id(),
+            algorithm: algorithm.to_owned(),
+            algorithm_family: family.to_owned(),
+            output,
+            witness,
+            artifact,
+        })
+    }
+}
+
+/// Builds a [`StructuredTestLog`] (and its forensics bundle index) from a run
+/// id and a test id.
+///
+/// Defaults: crate `fnx-algorithms`, packet id from the middle `::` segment of
+/// the test id upper-cased (`unit::fnx-p2c-005::contract` gives `FNX-P2C-005`;
+/// the run id when the test id has no such segment), a passed strict-mode unit
+/// test named after the last `::` segment of the test id, suite id from the
+/// test kind, `os`/`arch` environment keys, timestamp 1 and duration 0,
+/// `cargo test -p <crate_name> <test_name> -- --nocapture` as the replay
+/// command, and `structured_logs.jsonl` as the only artifact. The forensics
+/// bundle mirrors the log's ids, replay command and artifacts, and `hash_id`
+/// is a stable hash of the log's JSON form.
+#[derive(Debug, Clone)]
+pub struct StructuredLogBuilder {
+    run_id: String,
+    test_id: String,
+    test_name: Option<String>,
+    crate_name: String,
+    packet_id: Option<String>,
+    suite_id: Option<String>,
+    test_kind: TestKind,
+    mode: CompatibilityMode,
+    fixture_id: Option<String>,
+    seed: Option<u64>,
+    environment: BTreeMap<String, String>,
+    ts_unix_ms: u128,
+    duration_ms: u128,
+    replay_command: Option<String>,
+    artifact_refs: Vec<String>,
+    forensic_bundle_id: Option<String>,
+}
+
+impl StructuredLogBuilder {
+    #[must_use]
+    pub fn new(run_id: impl Into<String>, test_id: impl Into<String>) -> Self {
+        let environment = BTreeMap::from([
+            ("arch".to_owned(), std::env::consts::ARCH.to_owned()),
+            ("os".to_owned(), std::env::consts::OS.to_owned()),
+        ]);
+        Self {
+            run_id: run_id.into(),
+            test_id: test_id.into(),
+            test_name: None,
+            crate_name: env!("CARGO_PKG_NAME").to_owned(),
+            packet_id: None,
+            suite_id: None,
+            test_kind: TestKind::Unit,
+            mode: CompatibilityMode::Strict,
+            fixture_id: None,
+            seed: None,
+            environment,
+            ts_unix_ms: 1,
+            duration_ms: 0,
+            replay_command: None,
+            artifact_refs: vec!["artifacts/conformance/latest/structured_logs.jsonl".to_owned()],
+            forensic_bundle_id: None,
+        }
+    }
+
+    #[must_use]
+    pub fn with_test_name(mut self, test_name: impl Into<String>) -> Self {
+        self.test_name = Some(test_name.into());
+        self
+    }
+
+    #[must_use]
+    pub fn with_crate_name(mut self, crate_name: impl Into<String>) -> Self {
+        self.crate_name = crate_name.into();
+        self
+    }
+
+    #[must_use]
+    pub fn with_packet_id(mut self, packet_id: impl Into<String>) -> Self {
+        self.packet_id = Some(packet_id.into());
+        self
+    }
+
+    #[must_use]
+    pub fn with_suite_id(mut self, suite_id: impl Into<String>) -> Self {
+        self.suite_id = Some(suite_id.into());
+        self
+    }
+
+    #[must_use]
+    pub fn with_test_kind(mut self, test_kind: TestKind) -> Self {
+        self.test_kind = test_kind;
+        self
+    }
+
+    #[must_use]
+    pub fn with_mode(mut self, mode: CompatibilityMode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    #[must_use]
+    pub fn with_fixture_id(mut self, fixture_id: impl Into<String>) -> Self {
+        self.fixture_id = Some(fixture_id.into());
+        self
+    }
+
+    #[must_use]
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        self.seed = Some(seed);
+        self
+    }
+
+    /// Adds (or replaces) one environment key.
+    #[must_use]
+    pub fn with_env(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.environment.insert(key.into(), value.into());
+        self
+    }
+
+    #[must_use]
+    pub fn with_timing(mut self, ts_unix_ms: u128, duration_ms: u128) -> Self {
+        self.ts_unix_ms = ts_unix_ms;
+        self.duration_ms = duration_ms;
+        self
+    }
+
+    #[must_use]
+    pub fn with_replay_command(mut self, replay_command: impl Into<String>) -> Self {
+        self.replay_command = Some(replay_command.into());
+        self
+    }
+
+    /// Replaces the default artifact list.
+    #[must_use]
+    pub fn with_artifact_refs(mut self, artifact_refs: Vec<String>) -> Self {
+        self.artifact_refs = artifact_refs;
+        self
+    }
+
+    #[must_use]
+    pub fn with_forensic_bundle_id(mut self, forensic_bundle_id: impl Into<String>) -> Self {
+        self.forensic_bundle_id = Some(forensic_bundle_id.into());
+        self
+    }
+
+    /// Records `run`: its fixture id (unless one is already set) and the
+    /// `algorithm`, `algorithm_family` and `witness_hash_id` environment keys.
+    #[must_use]
+    pub fn with_run(mut self, run: &WitnessedRun) -> Self {
+        if self.fixture_id.is_none() {
+            self.fixture_id = Some(run.fixture_id.clone());
+        }
+        self.with_env("algorithm", run.algorithm.clone())
+            .with_env("algorithm_family", run.algorithm_family.clone())
+            .with_env("witness_hash_id", run.artifact.witness_hash_id.clone())
+    }
+
+    /// Assembles the log, fingerprints its environment and validates it.
+    pub fn build(self) -> Result<StructuredTestLog, String> {
+        let test_name = self.test_name.unwrap_or_else(|| {
+            self.test_id
+                .rsplit("::")
+                .next()
+                .unwrap_or_default()
+                .to_owned()
+        });
+        let packet_id = self.packet_id.unwrap_or_else(|| {
+            let segments = self.test_id.split("::").collect::<Vec<&str>>();
+            match segments.as_slice() {
+                [_, packet, _, ..] => packet.to_ascii_uppercase(),
+                _ => self.run_id.clone(),
+            }
+        });
+        let suite_id = self
+            .suite_id
+            .unwrap_or_else(|| test_kind_token(self.test_kind).to_owned());
+        let replay_command = self.replay_command.unwrap_or_else(|| {
+            format!(
+                "cargo test -p {} {test_name} -- --nocapture",
+                self.crate_name
+            )
+        });
+        let forensic_bundle_id = self
+            .forensic_bundle_id
+            .unwrap_or_else(|| format!("forensics::{}", self.run_id));
+        let bundle_hash_material = format!("{}|{}|{replay_command}", self.run_id, self.test_id);
+        let mut log = StructuredTestLog {
+            schema_version: structured_test_log_schema_version().to_owned(),
+            run_id: self.run_id.clone(),
+            ts_unix_ms: self.ts_unix_ms,
+            crate_name: self.crate_name,
+            suite_id,
+            packet_id,
+            test_name,
+            test_id: self.test_id.clone(),
+            test_kind: self.test_kind,
+            mode: self.mode,
+            fixture_id: self.fixture_id,
+            seed: self.seed,
+            env_fingerprint: canonical_environment_fingerprint(&self.environment),
+            environment: self.environment,
+            duration_ms: self.duration_ms,
+            replay_command: replay_command.clone(),
+            artifact_refs: self.artifact_refs.clone(),
+            forensic_bundle_id: forensic_bundle_id.clone(),
+            hash_id: String::new(),
+            status: TestStatus::Passed,
+            reason_code: None,
+            failure_repro: None,
+            e2e_step_traces: Vec::new(),
+            forensics_bundle_index: Some(ForensicsBundleIndex {
+                bundle_id: forensic_bundle_id,
+                run_id: self.run_id,
+                test_id: self.test_id,
+                bundle_hash_id: format!(
+                    "bundle-hash-{}",
+                    stable_hash_hex(bundle_hash_material.as_bytes())
+                ),
+                captured_unix_ms: self.ts_unix_ms,
+                replay_ref: replay_command,
+                artifact_refs: self.artifact_refs,
+                raptorq_sidecar_refs: Vec::new(),
+                decode_proof_refs: Vec::new(),
+            }),
+        };
+        // Hashed with `hash_id` still empty, so the id covers every other field.
+        let log_json = log.to_json_pretty().map_err(|error| error.to_string())?;
+        log.hash_id = format!("log-hash-{}", stable_hash_hex(log_json.as_bytes()));
+        log.validate()?;
+        Ok(log)
+    }
+}
+
+fn test_kind_token(kind: TestKind) -> &'static str {
+    match kind {
+        TestKind::Unit => "unit",
+        TestKind::Property => "property",
+        TestKind::Differential => "differential",
+        TestKind::E2e => "e2e",
+        TestKind::Fuzz => "fuzz",
+        TestKind::Perf => "perf",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn catalog_fixtures_match_pinned_sizes() {
+        let pinned = [
+            ("path", 5, 4),
+            ("cycle", 6, 6),
+            ("star", 5, 4),
+            ("two_triangles_bridge", 6, 7),
+            ("weighted_diamond", 4, 5),
+        ];
+        assert_eq!(
+            pinned.map(|(name, _, _)| name),
+            SCENARIO_FIXTURE_CATALOG,
+            "every catalog entry is pinned"
+        );
+        for (name, nodes, edges) in pinned {
+            let fixture = ScenarioFixture::new(name).expect("catalog fixture builds");
+            assert_eq!(fixture.graph.node_count(), nodes, "{name}");
+            assert_eq!(fixture.graph.edge_count(), edges, "{name}");
+            assert!(fixture.graph.has_node(&fixture.source), "{name}");
+            assert!(fixture.graph.has_node(&fixture.target), "{name}");
+        }
+        assert_eq!(
+            ScenarioFixture::new("petersen").err(),
+            Some(ScenarioError::UnknownFixture("petersen".to_owned()))
+        );
+    }
+
+    #[test]
+    fn full_scenarios_validate_for_three_algorithms() {
+        for (fixture_name, algorithm, family) in [
+            (
+                "weighted_diamond",
+                "shortest_path_weighted",
+                "shortest_path",
+            ),
+            ("star", "pagerank", "centrality"),
+            ("two_triangles_bridge", "bridges", "connectivity"),
+        ] {
+            let fixture = ScenarioFixture::new(fixture_name).expect("catalog fixture builds");
+            let run = WitnessedRun::execute(&fixture, algorithm).expect("algorithm is supported");
+            assert_eq!(run.algorithm_family, family);
+            assert_eq!(run.artifact.operation, algorithm);
+            assert_eq!(run.artifact.algorithm, run.witness.algorithm);
+            assert!(run.artifact.witness_hash_id.starts_with("cgse-witness:"));
+
+            let log = StructuredLogBuilder::new(
+                format!("algorithms-scenario-{fixture_name}"),
+                format!("unit::scenario::{algorithm}"),
+            )
+            .with_run(&run)
+            .build()
+            .expect("scenario log validates");
+            assert_eq!(log.crate_name, "fnx-algorithms");
+            assert_eq!(log.packet_id, "SCENARIO");
+            assert_eq!(log.test_name, algorithm);
+            assert_eq!(log.suite_id, "unit");
+            assert_eq!(
+                log.replay_command,
+                format!("cargo test -p fnx-algorithms {algorithm} -- --nocapture")
+            );
+            assert_eq!(log.fixture_id, Some(fixture.fixture_id()));
+            assert_eq!(
+                log.environment.get("witness_hash_id"),
+                Some(&run.artifact.witness_hash_id)
+            );
+            assert_eq!(
+                log.env_fingerprint,
+                canonical_environment_fingerprint(&log.environment)
+            );
+        }
+
+        let diamond = ScenarioFixture::new("weighted_diamond").expect("catalog fixture builds");
+        let weighted =
+            WitnessedRun::execute(&diamond, "shortest_path_weighted").expect("supported");
+        let hops = WitnessedRun::execute(&diamond, "shortest_path_unweighted").expect("supported");
+        let path = |names: &[&str]| {
+            ScenarioOutput::Path(Some(names.iter().map(|name| (*name).to_owned()).collect()))
+        };
+        assert_eq!(weighted.output, path(&["a", "b", "c", "d"]));
+        assert_eq!(hops.output, path(&["a", "b", "d"]));
+        assert_eq!(
+            WitnessedRun::execute(&diamond, "tsp").err(),
+            Some(ScenarioError::UnknownAlgorithm("tsp".to_owned()))
+        );
+    }
+
+    #[test]
+    fn builders_are_replay_stable() {
+        for fixture_name in SCENARIO_FIXTURE_CATALOG {
+            for algorithm in SCENARIO_ALGORITHMS {
+                let run = || {
+                    let fixture =
+                        ScenarioFixture::new(fixture_name).expect("catalog fixture builds");
+                    WitnessedRun::execute(&fixture, algorithm).expect("algorithm is supported")
+                };
+                let first = run();
+                assert_eq!(run(), first, "{fixture_name}/{algorithm}");
+
+                let log = |seed| {
+                    StructuredLogBuilder::new(
+                        "algorithms-scenario-replay",
+                        "unit::scenario::replay",
+                    )
+                    .with_run(&first)
+                    .with_seed(seed)
+                    .build()
+                    .expect("scenario log validates")
+                };
+                assert_eq!(
+                    log(7).to_json_pretty().expect("log serializes"),
+                    log(7).to_json_pretty().expect("log serializes")
+                );
+                assert_ne!(log(7).hash_id, log(8).hash_id);
+            }
+        }
+
+        let missing_ids = StructuredLogBuilder::new("", "unit::scenario::replay").build();
+        assert_eq!(
+            missing_ids.err().as_deref(),
+            Some("run_id must be non-empty")
+        );
+
+        let packet_log = StructuredLogBuilder::new("run", "unit::fnx-p2c-900::contract")
+            .build()
+            .expect("defaults alone validate");
+        assert_eq!(packet_log.crate_name, "fnx-algorithms");
+        assert_eq!(packet_log.packet_id, "FNX-P2C-900");
+        assert!(packet_log.hash_id.starts_with("log-hash-"));
+        let bare_log = StructuredLogBuilder::new("bare-run", "bare_test")
+            .with_crate_name("fnx-custom")
+            .build()
+            .expect("defaults alone validate");
+        assert_eq!(bare_log.packet_id, "bare-run");
+        assert_eq!(
+            bare_log.replay_command,
+            "cargo test -p fnx-custom bare_test -- --nocapture"
+        );
+    }
+}